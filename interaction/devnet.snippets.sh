@@ -35,12 +35,12 @@ restoreDeployData() {
   ADDRESS=$(mxpy data parse --file="./interaction/deploy-devnet.interaction.json" --expression="data['contractAddress']")
 }
 
-setClaimToken(){
+addClaimToken(){
     mxpy --verbose contract call ${ADDRESS} \
     --recall-nonce \
     --pem=${WALLET} \
     --gas-limit=6000000 \
-    --function "setClaimToken" \
+    --function "addClaimToken" \
     --arguments ${TOKEN_HEX} \
     --proxy ${PROXY} \
     --chain ${CHAIN_ID} \
@@ -69,61 +69,33 @@ unpause(){
     --send || return
 }
 
-addPrivilegedAddress(){
-    # $1 = address to which to give privileges
+grantRole(){
+    # $1 = role (0 = pauser, 1 = depositor, 2 = claim remover, 3 = unpauser, 4 = admin)
+    # $2 = address to which to grant the role
 
-    address="0x$(mxpy wallet bech32 --decode ${1})"
-    mxpy --verbose contract call ${ADDRESS} \
-    --recall-nonce \
-    --pem=${WALLET} \
-    --gas-limit=10000000 \
-    --function "addPrivilegedAddress" \
-    --arguments $address \
-    --proxy ${PROXY} \
-    --chain ${CHAIN_ID} \
-    --send || return
-}
-
-removePrivilegedAddress(){
-    # $1 = address to which to remove privileges
-
-    address="0x$(mxpy wallet bech32 --decode ${1})"
-    mxpy --verbose contract call ${ADDRESS} \
-    --recall-nonce \
-    --pem=${WALLET} \
-    --gas-limit=10000000 \
-    --function "removePrivilegedAddress" \
-    --arguments $address \
-    --proxy ${PROXY} \
-    --chain ${CHAIN_ID} \
-    --send || return
-}
-
-addDepositorAddress(){
-    # $1 = address to which to give privileges
-
-    address="0x$(mxpy wallet bech32 --decode ${1})"
+    address="0x$(mxpy wallet bech32 --decode ${2})"
     mxpy --verbose contract call ${ADDRESS} \
     --recall-nonce \
     --pem=${WALLET} \
     --gas-limit=10000000 \
-    --function "addDepositorAddress" \
-    --arguments $address \
+    --function "grantRole" \
+    --arguments ${1} $address \
     --proxy ${PROXY} \
     --chain ${CHAIN_ID} \
     --send || return
 }
 
-removeDepositorAddress(){
-    # $1 = address to which to remove privileges
+revokeRole(){
+    # $1 = role (0 = pauser, 1 = depositor, 2 = claim remover, 3 = unpauser, 4 = admin)
+    # $2 = address from which to revoke the role
 
-    address="0x$(mxpy wallet bech32 --decode ${1})"
+    address="0x$(mxpy wallet bech32 --decode ${2})"
     mxpy --verbose contract call ${ADDRESS} \
     --recall-nonce \
     --pem=${WALLET} \
     --gas-limit=10000000 \
-    --function "removeDepositorAddress" \
-    --arguments $address \
+    --function "revokeRole" \
+    --arguments ${1} $address \
     --proxy ${PROXY} \
     --chain ${CHAIN_ID} \
     --send || return
@@ -133,6 +105,7 @@ addClaim(){
     # $1 = amount to add to claim
     # $2 = address to which to attribute the claim
     # $3 = claim type (0 = reward, 1 = aidrop, 2 = allocation, 3 = royalties)
+    # $4 = (optional) timestamp after which the claim expires
 
     method="0x$(echo -n 'addClaim' | xxd -p -u | tr -d '\n')"
     address="0x$(mxpy wallet bech32 --decode ${2})"
@@ -141,7 +114,7 @@ addClaim(){
     --pem=${WALLET} \
     --gas-limit=6000000 \
     --function "ESDTTransfer" \
-    --arguments ${TOKEN_HEX} $1 $method $address $3 \
+    --arguments ${TOKEN_HEX} $1 $method $address $3 $4 \
     --proxy ${PROXY} \
     --chain ${CHAIN_ID} \
     --send || return
@@ -158,7 +131,7 @@ removeClaim(){
     --pem=${WALLET} \
     --gas-limit=6000000 \
     --function "removeClaim" \
-    --arguments $address $2 $3 \
+    --arguments $address $2 ${TOKEN_HEX} $3 \
     --proxy ${PROXY} \
     --chain ${CHAIN_ID} \
     --send || return
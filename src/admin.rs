@@ -0,0 +1,93 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    constants::*,
+    events, requirements,
+    storage::{self, AdminOperation, QueuedOperation},
+    views,
+};
+
+// Module that handles the admin of the smart contract, its two-step transfer and the timelock queue of the admin operations
+#[multiversx_sc::module]
+pub trait AdminModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+{
+    // Endpoint available for the admin of the smart contract to propose a new admin. The admin role is only transferred once the proposed address accepts it.
+    #[endpoint(proposeAdmin)]
+    fn propose_admin(&self, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.pending_admin().set(&address);
+        self.admin_proposed_event(&caller, &address);
+    }
+
+    // Endpoint available for the pending admin of the smart contract to accept the admin role proposed by the current admin.
+    #[endpoint(acceptAdmin)]
+    fn accept_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.pending_admin().is_empty() && self.pending_admin().get() == caller,
+            ERR_ADDRESS_NOT_PENDING_ADMIN
+        );
+
+        let previous_admin = self.admin().get();
+        self.admin().set(&caller);
+        self.pending_admin().clear();
+        self.admin_changed_event(&previous_admin, &caller);
+    }
+
+    // Endpoint available for the admin of the smart contract to set the delay after which the queued admin operations can be executed.
+    // Increasing the delay takes effect immediately, while decreasing it is an admin operation queued in the timelock.
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+
+        if delay < self.timelock_delay().get() {
+            self.queue_operation(AdminOperation::SetTimelockDelay { delay });
+            return;
+        }
+
+        self.timelock_delay().set(delay);
+        self.timelock_delay_set_event(delay);
+    }
+
+    // Endpoint available for privileged addresses of the smart contract to cancel an admin operation queued in the timelock before it is executed.
+    #[endpoint(cancelOperation)]
+    fn cancel_operation(&self, operation_id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_privileged(&caller);
+        require!(
+            self.queued_operations().remove(&operation_id).is_some(),
+            ERR_OPERATION_NOT_FOUND
+        );
+        self.operation_cancelled_event(&caller, operation_id);
+    }
+
+    // Checks whether the admin operations have to be queued in the timelock instead of being executed immediately
+    fn is_timelock_enabled(&self) -> bool {
+        self.timelock_delay().get() > 0
+    }
+
+    // Queues an admin operation in the timelock, from which it can be executed by the admin once the timelock delay has passed
+    fn queue_operation(&self, operation: AdminOperation<Self::Api>) -> u64 {
+        let operation_id = self.last_operation_id().get() + 1;
+        self.last_operation_id().set(operation_id);
+
+        let executable_from = self.blockchain().get_block_timestamp() + self.timelock_delay().get();
+        self.operation_queued_event(operation_id, executable_from, &operation);
+        self.queued_operations().insert(
+            operation_id,
+            QueuedOperation {
+                operation,
+                executable_from,
+            },
+        );
+
+        operation_id
+    }
+}
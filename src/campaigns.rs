@@ -0,0 +1,172 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    constants::*,
+    events, requirements,
+    storage::{self, split_proportionally, Campaign, CampaignShare, ClaimMovementKind, ClaimType},
+    views,
+};
+
+// Module that handles the campaigns, which label the claims added for them and track how much of their budget has been deposited, harvested and removed
+#[multiversx_sc::module]
+pub trait CampaignsModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+{
+    // Endpoint available for the admin of the smart contract to create a campaign, to which claims of the given claim type and token can be added up to the budget. Returns the identifier of the campaign.
+    #[endpoint(createCampaign)]
+    fn create_campaign(
+        &self,
+        name: ManagedBuffer,
+        claim_type: ClaimType,
+        token: EgldOrEsdtTokenIdentifier,
+        budget: BigUint,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.require_claim_type_is_registered(&claim_type);
+        self.require_token_is_whitelisted(&token);
+        self.require_value_not_zero(&budget);
+
+        let campaign_id = self.last_campaign_id().get() + 1;
+        self.last_campaign_id().set(campaign_id);
+        self.campaign_created_event(campaign_id, &claim_type, &token, &budget, &name);
+        self.campaign(campaign_id).set(Campaign {
+            name,
+            claim_type,
+            token,
+            budget,
+            deposited: BigUint::zero(),
+            harvested: BigUint::zero(),
+            removed: BigUint::zero(),
+        });
+
+        campaign_id
+    }
+
+    // Checks whether claims can be added to a campaign in the given token and returns the campaign
+    fn require_campaign_accepts_token(
+        &self,
+        campaign_id: u64,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> Campaign<Self::Api> {
+        require!(
+            !self.campaign(campaign_id).is_empty(),
+            ERR_CAMPAIGN_NOT_FOUND
+        );
+        let campaign = self.campaign(campaign_id).get();
+        require!(&campaign.token == token, ERR_CAMPAIGN_TOKEN_MISMATCH);
+
+        campaign
+    }
+
+    // Adds the amounts deposited for a campaign to its total, which must not exceed its budget
+    fn deposit_to_campaign(&self, campaign_id: u64, amount: &BigUint) {
+        let mut campaign = self.campaign(campaign_id).get();
+        campaign.deposited += amount;
+        require!(
+            campaign.deposited <= campaign.budget,
+            ERR_CAMPAIGN_BUDGET_EXCEEDED
+        );
+        self.campaign(campaign_id).set(campaign);
+    }
+
+    // Labels an amount added to a claim with the campaign for which it was added
+    fn add_claim_campaign(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        campaign_id: u64,
+        amount: &BigUint,
+    ) {
+        let mut claim_campaigns = self.claim_campaigns(address, claim_type, token);
+        let labeled = claim_campaigns.get(&campaign_id).unwrap_or_default();
+        claim_campaigns.insert(campaign_id, labeled + amount);
+        self.campaign_claim_added_event(campaign_id, address, token, amount);
+    }
+
+    // Takes an amount harvested or removed from a claim out of the campaigns of the claim, from each campaign proportionally to the part of the claim it labels, adds it to the harvested or removed totals of the campaigns and returns the amount taken from each campaign.
+    // The part of the claim that is not labeled with a campaign is not counted in any campaign
+    fn take_claim_campaigns(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        claim_amount: &BigUint,
+        amount: &BigUint,
+        kind: ClaimMovementKind,
+    ) -> ManagedVec<CampaignShare<Self::Api>> {
+        let mut taken = ManagedVec::new();
+        let mut claim_campaigns = self.claim_campaigns(address, claim_type, token);
+        if claim_campaigns.is_empty() {
+            return taken;
+        }
+
+        let mut campaign_ids = ManagedVec::<Self::Api, u64>::new();
+        let mut parts = ManagedVec::<Self::Api, BigUint>::new();
+        let mut labeled = BigUint::zero();
+        for (campaign_id, amount) in claim_campaigns.iter() {
+            labeled += &amount;
+            campaign_ids.push(campaign_id);
+            parts.push(amount);
+        }
+        if claim_amount > &labeled {
+            parts.push(claim_amount - &labeled);
+        }
+
+        let shares = split_proportionally(&parts, amount);
+        for (index, campaign_id) in campaign_ids.iter().enumerate() {
+            let share = (*shares.get(index)).clone();
+            if share == BigUint::zero() {
+                continue;
+            }
+
+            let part = (*parts.get(index)).clone();
+            if part > share {
+                claim_campaigns.insert(campaign_id, part - &share);
+            } else {
+                claim_campaigns.remove(&campaign_id);
+            }
+
+            let mut campaign = self.campaign(campaign_id).get();
+            match kind {
+                ClaimMovementKind::Harvested => {
+                    campaign.harvested += &share;
+                    self.campaign_claim_collected_event(campaign_id, address, token, &share);
+                }
+                _ => {
+                    campaign.removed += &share;
+                    self.campaign_claim_removed_event(campaign_id, address, token, &share);
+                }
+            }
+            self.campaign(campaign_id).set(campaign);
+            taken.push(CampaignShare {
+                campaign_id,
+                amount: share,
+            });
+        }
+
+        taken
+    }
+
+    // Restores the amounts of a harvested claim taken out of its campaigns, labeling the claim with them again and removing them from the harvested totals of the campaigns
+    fn restore_claim_campaigns(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        shares: &ManagedVec<CampaignShare<Self::Api>>,
+    ) {
+        let mut claim_campaigns = self.claim_campaigns(address, claim_type, token);
+        for share in shares.iter() {
+            let labeled = claim_campaigns.get(&share.campaign_id).unwrap_or_default();
+            claim_campaigns.insert(share.campaign_id, labeled + &share.amount);
+            self.campaign(share.campaign_id)
+                .update(|campaign| campaign.harvested -= &share.amount);
+        }
+    }
+}
@@ -1,10 +1,24 @@
 pub const MAX_NUMBER_OF_CLAIMS_PER_OPERATION: usize = 200;
-pub const MAX_NUMBER_OF_PRIVILEGED_ADDRESSES: usize = 2;
+pub const MAX_NUMBER_OF_CLAIM_TYPES: usize = 20;
+pub const MAX_CLAIM_HISTORY_LIMIT: u64 = 100;
+pub const MAX_CLAIM_EXPIRY_PERIOD: u64 = 31_536_000;
+
+pub const CLAIM_TYPE_REWARD: u8 = 0;
+pub const CLAIM_TYPE_AIRDROP: u8 = 1;
+pub const CLAIM_TYPE_ALLOCATION: u8 = 2;
+pub const CLAIM_TYPE_ROYALTY: u8 = 3;
+pub const DEFAULT_CLAIM_TYPES: [(u8, &str); 4] = [
+    (CLAIM_TYPE_REWARD, "Reward"),
+    (CLAIM_TYPE_AIRDROP, "Airdrop"),
+    (CLAIM_TYPE_ALLOCATION, "Allocation"),
+    (CLAIM_TYPE_ROYALTY, "Royalty"),
+];
 
 pub const ERR_ADDRESS_NOT_AUTHORIZED: &str = "Address not authorized to use this operation";
-pub const ERR_TOKEN_NOT_SET: &str = "Claims token is not set";
-pub const ERR_TOKEN_SET: &str = "Claims token is already set";
-pub const ERR_TOKEN_INCORRECT: &str = "Can only add designated token";
+pub const ERR_TOKEN_INVALID: &str = "Invalid token identifier";
+pub const ERR_TOKEN_WHITELISTED: &str = "Token is already whitelisted";
+pub const ERR_TOKEN_NOT_WHITELISTED: &str = "Token is not whitelisted";
+pub const ERR_TOKEN_NOT_NFT: &str = "Can only add NFTs or SFTs";
 pub const ERR_NON_ZERO_VALUE: &str = "Operation must have non-zero value";
 pub const ERR_MORE_THAN_CLAIM: &str = "Cannot remove more than current claim";
 pub const ERR_MAX_NUMBER_OF_CLAIMS_PER_OPERATION: &str =
@@ -12,13 +26,66 @@ pub const ERR_MAX_NUMBER_OF_CLAIMS_PER_OPERATION: &str =
 pub const ERR_CONTRACT_PAUSED: &str = "Contract is paused";
 pub const ERR_CONTRACT_ALREADY_PAUSED: &str = "Contract is already paused";
 pub const ERR_CONTRACT_ALREADY_UNPAUSED: &str = "Contract is already unpaused";
-pub const ERR_ADDRESS_PRIVILEGED: &str = "Address is already privileged";
-pub const ERR_ADDRESS_DEPOSITOR: &str = "Address is already a depositor";
-pub const ERR_ADDRESS_NOT_PRIVILEGED: &str = "Address is not privileged";
-pub const ERR_ADDRESS_NOT_DEPOSITOR: &str = "Address is not a depositor";
-pub const ERR_MAX_NUMBER_OF_PRIVILEGED_ADDRESSES: &str =
-    "Exceeded maximum number of privileged addresses";
-
-pub const ERR_OWNER_NOT_PRIVILEGED: &str = "Owner cannot be added to priviledged addresses";
-pub const ERR_OWNER_NOT_DEPOSITOR: &str = "Owner cannot be added to depositor addresses";
+pub const ERR_CLAIM_TYPE_PAUSED: &str = "Claim type is paused";
+pub const ERR_CLAIM_TYPE_ALREADY_PAUSED: &str = "Claim type is already paused";
+pub const ERR_CLAIM_TYPE_ALREADY_UNPAUSED: &str = "Claim type is already unpaused";
+pub const ERR_ROLE_ALREADY_GRANTED: &str = "Role is already granted to the address";
+pub const ERR_ROLE_NOT_GRANTED: &str = "Role is not granted to the address";
+pub const ERR_ADDRESS_NOT_ADMIN: &str = "Only the admin can call this endpoint";
+pub const ERR_ADDRESS_NOT_PENDING_ADMIN: &str = "Address is not the pending admin";
+pub const ERR_OPERATION_NOT_FOUND: &str = "Operation is not queued";
+pub const ERR_OPERATION_NOT_READY: &str = "Operation timelock has not passed";
 pub const ERR_CLAIM_EQUAL_PAYMENT: &str = "Claims added must equal payment amount";
+pub const ERR_CLAIM_TYPE_REGISTERED: &str = "Claim type is already registered";
+pub const ERR_CLAIM_TYPE_NOT_REGISTERED: &str = "Claim type is not registered";
+pub const ERR_CLAIM_TYPE_NOT_ENABLED: &str = "Claim type is not enabled";
+pub const ERR_CLAIM_TYPE_ALREADY_ENABLED: &str = "Claim type is already enabled";
+pub const ERR_CLAIM_TYPE_ALREADY_DISABLED: &str = "Claim type is already disabled";
+pub const ERR_MAX_NUMBER_OF_CLAIM_TYPES: &str = "Exceeded maximum number of claim types";
+pub const ERR_INVALID_VESTING_SCHEDULE: &str = "Invalid vesting schedule";
+pub const ERR_VESTING_SCHEDULE_ACTIVE: &str = "Claim already has an active vesting schedule";
+pub const ERR_INVALID_EXPIRY: &str = "Expiry must be in the future";
+pub const ERR_EXPIRY_TOO_FAR: &str = "Expiry is too far in the future";
+pub const ERR_CLAIM_EXPIRED: &str = "Claim has expired";
+pub const ERR_CLAIM_NOT_EXPIRED: &str = "Claim has not expired";
+pub const ERR_CLAIM_EXPIRY_SET_BY_OTHER_DEPOSITOR: &str =
+    "Claim has an expiry set by another depositor";
+pub const ERR_CLAIM_FUNDED_BY_OTHER_DEPOSITORS: &str =
+    "Can only set the expiry of a claim funded by the caller alone";
+pub const ERR_DISTRIBUTION_NOT_FOUND: &str = "Distribution does not exist";
+pub const ERR_DISTRIBUTION_LEAF_CLAIMED: &str = "Distribution leaf already claimed";
+pub const ERR_DISTRIBUTION_EXHAUSTED: &str = "Distribution does not have enough funds";
+pub const ERR_DISTRIBUTION_ENDED: &str = "Distribution deadline has passed";
+pub const ERR_DISTRIBUTION_NOT_ENDED: &str = "Distribution deadline has not passed";
+pub const ERR_INVALID_PROOF: &str = "Invalid proof";
+pub const ERR_VOUCHER_SIGNER_NOT_SET: &str = "Voucher signer is not set";
+pub const ERR_VOUCHER_EXPIRED: &str = "Voucher has expired";
+pub const ERR_VOUCHER_NONCE_USED: &str = "Voucher nonce already used";
+pub const ERR_VOUCHER_POOL_INSUFFICIENT: &str = "Voucher pool does not have enough funds";
+pub const ERR_INVALID_SIGNATURE: &str = "Invalid signature";
+pub const ERR_RELAYED_CLAIM_EXPIRED: &str = "Relayed claim authorization has expired";
+pub const ERR_NO_SURPLUS: &str = "No surplus above the reserved amount";
+pub const ERR_RESCUE_MORE_THAN_SURPLUS: &str =
+    "Can only rescue the surplus above the reserved amount";
+pub const ERR_RESCUE_RESERVED_NFT: &str = "Cannot rescue NFTs or SFTs reserved by claims";
+pub const ERR_EGLD_NONCE_NOT_ZERO: &str = "EGLD can only be rescued with a zero nonce";
+pub const ERR_DEPOSITOR_CLAIM_TYPE_NOT_ALLOWED: &str =
+    "Depositor is not allowed to add claims of this claim type";
+pub const ERR_DEPOSITOR_QUOTA_EXCEEDED: &str = "Depositor quota exceeded";
+pub const ERR_DEPOSITOR_QUOTA_NOT_SET: &str = "Depositor has no quota for this token";
+pub const ERR_RELAYER_FEE_TOO_HIGH: &str = "Relayer fee exceeds the harvested amount";
+pub const ERR_NOTHING_TO_CLAW_BACK: &str = "Nothing to claw back";
+pub const ERR_CLAWBACK_GRACE_PERIOD_OVER: &str = "Clawback grace period is over";
+pub const ERR_HARVEST_AMOUNT_WITHOUT_TOKEN: &str =
+    "A token must be given to harvest a specific amount";
+pub const ERR_NOT_A_SMART_CONTRACT: &str = "Address is not a smart contract";
+pub const ERR_STAKING_CONTRACT_NOT_SET: &str = "No staking contract is set for this token";
+pub const ERR_EXECUTE_TARGET_WHITELISTED: &str = "Target function is already whitelisted";
+pub const ERR_EXECUTE_TARGET_NOT_WHITELISTED: &str = "Target function is not whitelisted";
+pub const ERR_CLAIM_HISTORY_LIMIT_TOO_HIGH: &str = "Claim history limit is too high";
+pub const ERR_CAMPAIGN_NOT_FOUND: &str = "Campaign not found";
+pub const ERR_CAMPAIGN_TOKEN_MISMATCH: &str = "Token does not match the campaign token";
+pub const ERR_CAMPAIGN_CLAIM_TYPE_MISMATCH: &str =
+    "Claim type does not match the campaign claim type";
+pub const ERR_CAMPAIGN_BUDGET_EXCEEDED: &str = "Campaign budget exceeded";
+pub const ERR_RESERVED_TOTAL_EXCEEDED: &str = "Amount exceeds the total reserved";
@@ -0,0 +1,424 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    campaigns,
+    constants::*,
+    events, requirements,
+    storage::{
+        self, split_proportionally, ClaimDeposit, ClaimFunding, ClaimMovementKind, ClaimType,
+        DepositorQuota, DepositorQuotaUsage, HarvestedClaim,
+    },
+    views,
+};
+
+// Module that handles the restrictions of the depositors, which can be limited to specific claim types and to a quota of each token
+#[multiversx_sc::module]
+pub trait DepositorsModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + campaigns::CampaignsModule
+{
+    // Endpoint available for the admin of the smart contract to restrict a depositor to specific claim types. Giving no claim types allows the depositor to add claims of any claim type.
+    #[endpoint(setDepositorClaimTypes)]
+    fn set_depositor_claim_types(
+        &self,
+        depositor: ManagedAddress,
+        claim_types: MultiValueEncoded<ClaimType>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+
+        let mut depositor_claim_types = self.depositor_claim_types(&depositor);
+        depositor_claim_types.clear();
+        let claim_types = claim_types.to_vec();
+        for claim_type in claim_types.iter() {
+            self.require_claim_type_is_registered(&claim_type);
+            depositor_claim_types.insert(claim_type);
+        }
+        self.depositor_claim_types_set_event(&depositor, &claim_types);
+    }
+
+    // Endpoint available for the admin of the smart contract to limit the amount of a token that a depositor can add to claims, distributions and voucher pools, either in total or in each epoch.
+    // The amount already added against a previous quota of the token is kept.
+    #[endpoint(setDepositorQuota)]
+    fn set_depositor_quota(
+        &self,
+        depositor: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        per_epoch: bool,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.require_token_is_whitelisted(&token);
+
+        self.depositor_quota_set_event(&depositor, &token, &amount, per_epoch);
+        self.depositor_quota(&depositor, &token)
+            .set(DepositorQuota { amount, per_epoch });
+    }
+
+    // Endpoint available for the admin of the smart contract to remove the quota of a depositor for a token, allowing it to add any amount of the token to claims.
+    #[endpoint(clearDepositorQuota)]
+    fn clear_depositor_quota(&self, depositor: ManagedAddress, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        require!(
+            !self.depositor_quota(&depositor, &token).is_empty(),
+            ERR_DEPOSITOR_QUOTA_NOT_SET
+        );
+
+        self.depositor_quota(&depositor, &token).clear();
+        self.depositor_quota_usage(&depositor, &token).clear();
+        self.depositor_quota_cleared_event(&depositor, &token);
+    }
+
+    // Endpoint available for the admin of the smart contract to set the period after each deposit during which the depositors can claw it back. Setting it to zero disables clawbacks.
+    #[endpoint(setClawbackGracePeriod)]
+    fn set_clawback_grace_period(&self, grace_period: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.clawback_grace_period().set(grace_period);
+        self.clawback_grace_period_set_event(grace_period);
+    }
+
+    // Endpoint available for the depositors to claw back the tokens they added to a claim whose grace period is not over, as long as the claim has not been harvested since. The tokens are returned to the depositor.
+    #[endpoint(clawbackClaim)]
+    fn clawback_claim(
+        &self,
+        address: ManagedAddress,
+        claim_type: ClaimType,
+        token: EgldOrEsdtTokenIdentifier,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let mut claim_deposits = self.claim_deposits(&address, &claim_type, &token, &caller);
+        require!(!claim_deposits.is_empty(), ERR_NOTHING_TO_CLAW_BACK);
+        let mut deposit_amount = BigUint::zero();
+        for deposit in claim_deposits.iter() {
+            if self.is_within_clawback_grace_period(deposit.timestamp) {
+                deposit_amount += deposit.amount;
+            }
+        }
+        require!(deposit_amount > 0, ERR_CLAWBACK_GRACE_PERIOD_OVER);
+
+        // Only the part of the deposit still funded by the depositor can be clawed back, as the claim may have been partially removed since
+        let mut claim_funding = self.claim_funding(&address, &claim_type, &token);
+        let funded = claim_funding.get(&caller).unwrap_or_default();
+        let current_claim = self.get_claim(&address, &claim_type, &token);
+        let mut amount = deposit_amount;
+        if amount > funded {
+            amount = funded.clone();
+        }
+        if amount > current_claim {
+            amount = current_claim.clone();
+        }
+        require!(amount > 0, ERR_NOTHING_TO_CLAW_BACK);
+
+        claim_deposits.clear();
+        if funded > amount {
+            claim_funding.insert(caller.clone(), funded - &amount);
+        } else {
+            claim_funding.remove(&caller);
+        }
+        self.take_claim_campaigns(
+            &address,
+            &claim_type,
+            &token,
+            &current_claim,
+            &amount,
+            ClaimMovementKind::Removed,
+        );
+        self.set_claim(&address, &claim_type, &token, current_claim - &amount);
+        self.decrease_reserved(Some(&claim_type), &token, &amount);
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.claim_modify_date(&address, &claim_type).set(timestamp);
+        self.record_claim_movement(
+            &address,
+            &claim_type,
+            &token,
+            &amount,
+            ClaimMovementKind::Removed,
+        );
+        self.claim_clawed_back_event(&caller, &address, &claim_type, &token, &amount);
+
+        self.send().direct(&caller, &token, 0, &amount);
+    }
+
+    // View that returns the amount of a token that a depositor can still add to claims, in total or in the current epoch depending on its quota. Returns nothing if the depositor has no quota for the token.
+    #[view(viewRemainingDepositorQuota)]
+    fn view_remaining_depositor_quota(
+        &self,
+        depositor: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> OptionalValue<BigUint> {
+        if self.depositor_quota(depositor, token).is_empty() {
+            return OptionalValue::None;
+        }
+
+        let quota = self.depositor_quota(depositor, token).get();
+        let used = self.get_depositor_quota_used(depositor, token, &quota);
+        if used >= quota.amount {
+            return OptionalValue::Some(BigUint::zero());
+        }
+
+        OptionalValue::Some(quota.amount - used)
+    }
+
+    // Adds an amount of a token to the amount added by a depositor against its quota, if it has one for the token. Panics if the quota is exceeded
+    fn use_depositor_quota(
+        &self,
+        depositor: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        if self.depositor_quota(depositor, token).is_empty() {
+            return;
+        }
+
+        let quota = self.depositor_quota(depositor, token).get();
+        let used = self.get_depositor_quota_used(depositor, token, &quota) + amount;
+        require!(used <= quota.amount, ERR_DEPOSITOR_QUOTA_EXCEEDED);
+
+        self.depositor_quota_usage(depositor, token)
+            .set(DepositorQuotaUsage {
+                amount: used,
+                epoch: self.blockchain().get_block_epoch(),
+            });
+    }
+
+    // Returns the amount of a token added by a depositor against its quota, only counting the current epoch for the quotas that apply to each epoch
+    fn get_depositor_quota_used(
+        &self,
+        depositor: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        quota: &DepositorQuota<Self::Api>,
+    ) -> BigUint {
+        if self.depositor_quota_usage(depositor, token).is_empty() {
+            return BigUint::zero();
+        }
+
+        let usage = self.depositor_quota_usage(depositor, token).get();
+        if quota.per_epoch && usage.epoch != self.blockchain().get_block_epoch() {
+            return BigUint::zero();
+        }
+
+        usage.amount
+    }
+
+    // Adds an amount to the part of a claim funded by a depositor
+    fn add_claim_funding(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        depositor: &ManagedAddress,
+        amount: &BigUint,
+    ) {
+        let mut claim_funding = self.claim_funding(address, claim_type, token);
+        let funded = claim_funding.get(depositor).unwrap_or_default();
+        claim_funding.insert(depositor.clone(), funded + amount);
+
+        // Each deposit keeps its own grace period, so later deposits do not extend the grace period of the previous ones.
+        // The previous deposits whose grace period is over are dropped, as they can no longer be clawed back
+        if self.clawback_grace_period().get() == 0 {
+            return;
+        }
+        let mut claim_deposits = self.claim_deposits(address, claim_type, token, depositor);
+        let mut pending_deposits = ManagedVec::<Self::Api, ClaimDeposit<Self::Api>>::new();
+        for deposit in claim_deposits.iter() {
+            if self.is_within_clawback_grace_period(deposit.timestamp) {
+                pending_deposits.push(deposit);
+            }
+        }
+        if pending_deposits.len() < claim_deposits.len() {
+            claim_deposits.clear();
+            for deposit in pending_deposits.iter() {
+                claim_deposits.push(&deposit);
+            }
+        }
+        claim_deposits.push(&ClaimDeposit {
+            amount: amount.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+    }
+
+    // Clears the deposits of a claim, which can no longer be clawed back once the claim is harvested or returned
+    fn clear_claim_deposits(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) {
+        for depositor in self.claim_funding(address, claim_type, token).keys() {
+            self.claim_deposits(address, claim_type, token, &depositor)
+                .clear();
+        }
+    }
+
+    // Checks whether the claim of an address and claim type is entirely funded by the given depositor in all the whitelisted tokens and in all its NFTs and SFTs
+    fn is_claim_funded_only_by(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        depositor: &ManagedAddress,
+    ) -> bool {
+        for token in self.claim_tokens().iter() {
+            let claim = self.get_claim(address, claim_type, &token);
+            let funded = self
+                .claim_funding(address, claim_type, &token)
+                .get(depositor)
+                .unwrap_or_default();
+            if claim != funded {
+                return false;
+            }
+        }
+
+        for (nft_nonce, amount) in self.nft_claims(address, claim_type).iter() {
+            let funded = self
+                .nft_claim_funding(address, claim_type, &nft_nonce)
+                .get(depositor)
+                .unwrap_or_default();
+            if amount != funded {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // Restores the tokens harvested from the claims of an address to the claims they were taken from when forwarding them failed,
+    // attributing them again to the depositors that funded them and to the campaigns for which they were added
+    fn restore_harvested_claims(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        harvested_claims: &ManagedVec<HarvestedClaim<Self::Api>>,
+    ) {
+        let timestamp = self.blockchain().get_block_timestamp();
+        for harvested_claim in harvested_claims.iter() {
+            let claim = self.get_claim(address, &harvested_claim.claim_type, token);
+            self.set_claim(
+                address,
+                &harvested_claim.claim_type,
+                token,
+                claim + &harvested_claim.amount,
+            );
+            self.increase_reserved(
+                Some(&harvested_claim.claim_type),
+                token,
+                &harvested_claim.amount,
+            );
+            self.claim_modify_date(address, &harvested_claim.claim_type)
+                .set(timestamp);
+            self.restore_claim_funding(
+                address,
+                &harvested_claim.claim_type,
+                token,
+                &harvested_claim.fundings,
+            );
+            self.restore_claim_campaigns(
+                address,
+                &harvested_claim.claim_type,
+                token,
+                &harvested_claim.campaigns,
+            );
+            self.record_claim_movement(
+                address,
+                &harvested_claim.claim_type,
+                token,
+                &harvested_claim.amount,
+                ClaimMovementKind::Added,
+            );
+        }
+    }
+
+    // Restores the amounts of a harvested claim taken out of its funding ledger, attributing them again to the depositors they were taken from
+    fn restore_claim_funding(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        fundings: &ManagedVec<ClaimFunding<Self::Api>>,
+    ) {
+        let mut claim_funding = self.claim_funding(address, claim_type, token);
+        for funding in fundings.iter() {
+            let funded = claim_funding.get(&funding.depositor).unwrap_or_default();
+            claim_funding.insert(funding.depositor, funded + &funding.amount);
+        }
+    }
+
+    // Checks whether a deposit made at the given timestamp can still be clawed back
+    fn is_within_clawback_grace_period(&self, timestamp: u64) -> bool {
+        let grace_period = self.clawback_grace_period().get();
+        grace_period > 0 && self.blockchain().get_block_timestamp() <= timestamp + grace_period
+    }
+
+    // Takes an amount out of the funding of a claim, from each depositor proportionally to the part of the claim it funded, and returns the amount taken from each depositor.
+    // The part of the claim that is not in the ledger is attributed to the admin
+    fn take_claim_funding(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        claim_amount: &BigUint,
+        amount: &BigUint,
+    ) -> ManagedVec<ClaimFunding<Self::Api>> {
+        self.take_funding(
+            self.claim_funding(address, claim_type, token),
+            claim_amount,
+            amount,
+        )
+    }
+
+    // Takes an amount out of a funding ledger, from each depositor proportionally to the part it funded, and returns the amount taken from each depositor.
+    // The part of the claim amount that is not in the ledger is attributed to the admin, whose share comes after the shares of the depositors in the ledger
+    fn take_funding(
+        &self,
+        mut claim_funding: MapMapper<ManagedAddress, BigUint>,
+        claim_amount: &BigUint,
+        amount: &BigUint,
+    ) -> ManagedVec<ClaimFunding<Self::Api>> {
+        let mut fundings = ManagedVec::<Self::Api, ClaimFunding<Self::Api>>::new();
+        let mut funded = BigUint::zero();
+        for (depositor, amount) in claim_funding.iter() {
+            funded += &amount;
+            fundings.push(ClaimFunding { depositor, amount });
+        }
+        let ledger_len = fundings.len();
+        if claim_amount > &funded {
+            fundings.push(ClaimFunding {
+                depositor: self.admin().get(),
+                amount: claim_amount - &funded,
+            });
+        }
+
+        let mut parts = ManagedVec::<Self::Api, BigUint>::new();
+        for funding in fundings.iter() {
+            parts.push(funding.amount);
+        }
+        let shares = split_proportionally(&parts, amount);
+        let mut taken = ManagedVec::<Self::Api, ClaimFunding<Self::Api>>::new();
+        for (index, funding) in fundings.iter().enumerate() {
+            taken.push(ClaimFunding {
+                depositor: funding.depositor,
+                amount: (*shares.get(index)).clone(),
+            });
+        }
+
+        // Update the ledger, which does not include the part attributed to the admin
+        for index in 0..ledger_len {
+            let share = taken.get(index);
+            let funded = fundings.get(index).amount;
+            if funded > share.amount {
+                claim_funding.insert(share.depositor, funded - &share.amount);
+            } else {
+                claim_funding.remove(&share.depositor);
+            }
+        }
+
+        taken
+    }
+}
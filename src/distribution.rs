@@ -0,0 +1,203 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    campaigns,
+    constants::*,
+    depositors, events, requirements,
+    storage::{self, ClaimMovementKind, ClaimType, Distribution},
+    views,
+};
+
+// Module that handles distributions, which reserve tokens for a large number of addresses through a single Merkle root instead of one claim per address
+#[multiversx_sc::module]
+pub trait DistributionModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + campaigns::CampaignsModule
+    + depositors::DepositorsModule
+{
+    // Endpoint available for privileged addresses of the smart contract to fund a distribution of a specific claim type in any of the whitelisted tokens. Returns the identifier of the created distribution.
+    // Each leaf of the Merkle tree is the keccak256 hash of the leaf index (8 bytes, big endian), the address (32 bytes) and the amount (big endian), concatenated.
+    // An optional deadline can be given, after which the distribution can no longer be harvested and its unclaimed tokens can be returned to the depositor through the closeDistribution endpoint.
+    #[payable("*")]
+    #[endpoint(createDistribution)]
+    fn create_distribution(
+        &self,
+        claim_type: ClaimType,
+        root: ManagedByteArray<32>,
+        deadline: OptionalValue<u64>,
+    ) -> u64 {
+        self.require_claim_type_is_enabled(&claim_type);
+        let deadline = deadline.into_option().unwrap_or_default();
+        if deadline > 0 {
+            self.require_expiry_is_valid(deadline);
+        }
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_token_is_whitelisted(&payment_token);
+        self.require_value_not_zero(&payment_amount);
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_deposit_rights(&caller);
+        self.require_depositor_can_add_claim_type(&caller, &claim_type);
+        self.use_depositor_quota(&caller, &payment_token, &payment_amount);
+
+        let distribution_id = self.last_distribution_id().get() + 1;
+        self.last_distribution_id().set(distribution_id);
+        self.distribution(distribution_id).set(Distribution {
+            claim_type,
+            token: payment_token.clone(),
+            root,
+            depositor: caller.clone(),
+            amount: payment_amount.clone(),
+            claimed_amount: BigUint::zero(),
+            deadline,
+        });
+        self.increase_reserved(Some(&claim_type), &payment_token, &payment_amount);
+        self.distribution_created_event(
+            distribution_id,
+            &caller,
+            &claim_type,
+            &payment_token,
+            &payment_amount,
+        );
+
+        distribution_id
+    }
+
+    // Endpoint available for the public to harvest the tokens reserved for the calling address in a distribution, by providing its leaf index, amount and Merkle proof.
+    // Each leaf can only be harvested once. Cannot be called while harvesting is paused or once the deadline of the distribution has passed.
+    #[endpoint(claimDistribution)]
+    fn claim_distribution(
+        &self,
+        distribution_id: u64,
+        index: u64,
+        amount: BigUint,
+        proof: MultiValueEncoded<ManagedByteArray<32>>,
+    ) {
+        require!(!self.is_paused().get(), ERR_CONTRACT_PAUSED);
+        self.require_value_not_zero(&amount);
+
+        let distribution_mapper = self.distribution(distribution_id);
+        require!(!distribution_mapper.is_empty(), ERR_DISTRIBUTION_NOT_FOUND);
+        require!(
+            !self.is_distribution_leaf_claimed(distribution_id, index),
+            ERR_DISTRIBUTION_LEAF_CLAIMED
+        );
+
+        let caller = self.blockchain().get_caller();
+        let mut distribution = distribution_mapper.get();
+        self.require_claim_type_not_paused(&distribution.claim_type);
+        require!(
+            !self.is_distribution_ended(&distribution),
+            ERR_DISTRIBUTION_ENDED
+        );
+
+        // Rebuild the leaf from the arguments and hash it up to the root, sorting each pair of nodes before hashing them
+        let mut leaf = ManagedBuffer::new_from_bytes(&index.to_be_bytes());
+        leaf.append(caller.as_managed_buffer());
+        leaf.append(&amount.to_bytes_be_buffer());
+        let mut node = self.crypto().keccak256(&leaf);
+        for sibling in proof.into_iter() {
+            let mut pair = ManagedBuffer::new();
+            if node.to_byte_array() <= sibling.to_byte_array() {
+                pair.append(node.as_managed_buffer());
+                pair.append(sibling.as_managed_buffer());
+            } else {
+                pair.append(sibling.as_managed_buffer());
+                pair.append(node.as_managed_buffer());
+            }
+            node = self.crypto().keccak256(&pair);
+        }
+        require!(node == distribution.root, ERR_INVALID_PROOF);
+
+        distribution.claimed_amount += &amount;
+        require!(
+            distribution.claimed_amount <= distribution.amount,
+            ERR_DISTRIBUTION_EXHAUSTED
+        );
+        distribution_mapper.set(&distribution);
+        self.decrease_reserved(Some(&distribution.claim_type), &distribution.token, &amount);
+
+        // Mark the leaf as claimed in the bitmap of the distribution
+        let word_index = index / 64;
+        let word = self
+            .distribution_claimed_leaves(distribution_id, word_index)
+            .get();
+        self.distribution_claimed_leaves(distribution_id, word_index)
+            .set(word | (1u64 << (index % 64)));
+
+        self.distribution_claimed_event(
+            distribution_id,
+            &caller,
+            index,
+            &distribution.token,
+            &amount,
+        );
+        self.record_claim_movement(
+            &caller,
+            &distribution.claim_type,
+            &distribution.token,
+            &amount,
+            ClaimMovementKind::Harvested,
+        );
+        self.claim_collected_event(
+            &caller,
+            &distribution.claim_type,
+            &distribution.token,
+            &amount,
+        );
+
+        self.send().direct(&caller, &distribution.token, 0, &amount);
+    }
+
+    // Endpoint available for the admin of the smart contract and the depositor of a distribution to close it once its deadline has passed, returning the tokens that were not harvested to the depositor.
+    #[endpoint(closeDistribution)]
+    fn close_distribution(&self, distribution_id: u64) {
+        let distribution_mapper = self.distribution(distribution_id);
+        require!(!distribution_mapper.is_empty(), ERR_DISTRIBUTION_NOT_FOUND);
+
+        let mut distribution = distribution_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.admin().get() || caller == distribution.depositor,
+            ERR_ADDRESS_NOT_AUTHORIZED
+        );
+        require!(
+            self.is_distribution_ended(&distribution),
+            ERR_DISTRIBUTION_NOT_ENDED
+        );
+
+        let unclaimed_amount = &distribution.amount - &distribution.claimed_amount;
+        require!(unclaimed_amount > 0, ERR_DISTRIBUTION_EXHAUSTED);
+        distribution.amount = distribution.claimed_amount.clone();
+        distribution_mapper.set(&distribution);
+        self.decrease_reserved(
+            Some(&distribution.claim_type),
+            &distribution.token,
+            &unclaimed_amount,
+        );
+
+        self.distribution_closed_event(
+            distribution_id,
+            &distribution.depositor,
+            &distribution.token,
+            &unclaimed_amount,
+        );
+        self.send().direct(
+            &distribution.depositor,
+            &distribution.token,
+            0,
+            &unclaimed_amount,
+        );
+    }
+
+    // Checks whether the deadline of a distribution has passed, a distribution without deadline never ending
+    fn is_distribution_ended(&self, distribution: &Distribution<Self::Api>) -> bool {
+        distribution.deadline > 0
+            && distribution.deadline <= self.blockchain().get_block_timestamp()
+    }
+}
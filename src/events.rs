@@ -1,7 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::storage::ClaimType;
+use crate::storage::{AdminOperation, ClaimType, Role};
 
 // Module that handles event emitting for important smart contract events in order to facilitate logging, debugging and monitoring with ease
 #[multiversx_sc::module]
@@ -10,25 +10,62 @@ pub trait EventsModule {
     #[event("harvestPaused")]
     fn harvest_paused_event(&self, #[indexed] operator: &ManagedAddress);
 
-    // Emitted whenever the owner unpauses claim harvesting
+    // Emitted whenever the admin unpauses claim harvesting
     #[event("harvestUnpaused")]
     fn harvest_unpaused_event(&self);
 
-    // Emitted whenever the owner adds a privileged address
-    #[event("privilegedAddressAdded")]
-    fn privileged_address_added_event(&self, #[indexed] address: &ManagedAddress);
+    // Emitted whenever a privileged address pauses the harvesting of a claim type
+    #[event("claimTypePaused")]
+    fn claim_type_paused_event(
+        &self,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+    );
 
-    // Emitted whenever the owner removes a privileged address
-    #[event("privilegedAddressRemoved")]
-    fn privileged_address_removed_event(&self, #[indexed] address: &ManagedAddress);
+    // Emitted whenever the admin unpauses the harvesting of a claim type
+    #[event("claimTypeUnpaused")]
+    fn claim_type_unpaused_event(&self, #[indexed] claim_type: &ClaimType);
 
-    // Emitted whenever the owner sets the address (e.g. Data NFT marketplace contract)
-    #[event("depositorAddressAdded")]
-    fn depositor_address_added_event(&self, #[indexed] address: &ManagedAddress);
+    // Emitted whenever a role is granted to an address
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &Role,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] operator: &ManagedAddress,
+    );
+
+    // Emitted whenever a role is revoked from an address
+    #[event("roleRevoked")]
+    fn role_revoked_event(&self, #[indexed] role: &Role, #[indexed] address: &ManagedAddress);
+
+    // Emitted whenever an address renounces one of its roles
+    #[event("roleRenounced")]
+    fn role_renounced_event(&self, #[indexed] role: &Role, #[indexed] address: &ManagedAddress);
 
-    // Emitted whenever the owner clears the address (e.g. Data NFT marketplace contract)
-    #[event("depositorAddressRemoved")]
-    fn depositor_address_removed_event(&self, #[indexed] address: &ManagedAddress);
+    // Emitted whenever the admin adds a token to the whitelist of claim tokens
+    #[event("claimTokenAdded")]
+    fn claim_token_added_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier);
+
+    // Emitted whenever the admin removes a token from the whitelist of claim tokens
+    #[event("claimTokenRemoved")]
+    fn claim_token_removed_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier);
+
+    // Emitted whenever the admin registers a claim type
+    #[event("claimTypeAdded")]
+    fn claim_type_added_event(
+        &self,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] name: &ManagedBuffer,
+    );
+
+    // Emitted whenever the admin enables or disables a claim type
+    #[event("claimTypeEnabledSet")]
+    fn claim_type_enabled_set_event(
+        &self,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] enabled: bool,
+    );
 
     // Emitted whenever a new claim is added to the smart contract
     #[event("claimAdded")]
@@ -37,6 +74,7 @@ pub trait EventsModule {
         #[indexed] operator: &ManagedAddress,
         #[indexed] address: &ManagedAddress,
         #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: &BigUint,
     );
 
@@ -46,7 +84,9 @@ pub trait EventsModule {
         &self,
         #[indexed] address: &ManagedAddress,
         #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: &BigUint,
+        #[indexed] refund_recipient: &ManagedAddress,
     );
 
     // Emitted whenever an address harvests a claim from the smart contract
@@ -55,6 +95,397 @@ pub trait EventsModule {
         &self,
         #[indexed] address: &ManagedAddress,
         #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an NFT or SFT claim is added to the smart contract
+    #[event("nftClaimAdded")]
+    fn nft_claim_added_event(
+        &self,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an NFT or SFT claim is removed from the smart contract
+    #[event("nftClaimRemoved")]
+    fn nft_claim_removed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+        #[indexed] refund_recipient: &ManagedAddress,
+    );
+
+    // Emitted whenever an address harvests an NFT or SFT claim from the smart contract
+    #[event("nftClaimCollected")]
+    fn nft_claim_collected_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the NFTs or SFTs of an expired claim are returned to its depositor
+    #[event("nftClaimExpired")]
+    fn nft_claim_expired_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an address sets the beneficiary of its claims, for all claim types or for a single claim type
+    #[event("beneficiarySet")]
+    fn beneficiary_set_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &Option<ClaimType>,
+        #[indexed] beneficiary: &ManagedAddress,
+    );
+
+    // Emitted whenever an address clears the beneficiary of its claims, for all claim types or for a single claim type
+    #[event("beneficiaryCleared")]
+    fn beneficiary_cleared_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &Option<ClaimType>,
+    );
+
+    // Emitted whenever the claims harvested by an address are sent to a different receiver
+    #[event("claimRedirected")]
+    fn claim_redirected_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] receiver: &ManagedAddress,
+    );
+
+    // Emitted whenever a relayer harvests the claims of an address with its signed authorization
+    #[event("claimRelayed")]
+    fn claim_relayed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] relayer: &ManagedAddress,
+        #[indexed] nonce: u64,
+        #[indexed] fee_token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] fee_amount: &BigUint,
+    );
+
+    // Emitted whenever a vested claim is added to the smart contract
+    #[event("vestingScheduleAdded")]
+    fn vesting_schedule_added_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] start: u64,
+        #[indexed] cliff: u64,
+        #[indexed] end: u64,
+    );
+
+    // Emitted whenever the tokens of an expired claim are returned to its depositor
+    #[event("claimExpired")]
+    fn claim_expired_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a distribution is created
+    #[event("distributionCreated")]
+    fn distribution_created_event(
+        &self,
+        #[indexed] distribution_id: u64,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an address harvests its leaf of a distribution
+    #[event("distributionClaimed")]
+    fn distribution_claimed_event(
+        &self,
+        #[indexed] distribution_id: u64,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] index: u64,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a distribution is closed after its deadline and its unclaimed tokens are returned to its depositor
+    #[event("distributionClosed")]
+    fn distribution_closed_event(
+        &self,
+        #[indexed] distribution_id: u64,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever a privileged address sets the voucher signer
+    #[event("voucherSignerSet")]
+    fn voucher_signer_set_event(
+        &self,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] signer: &ManagedByteArray<32>,
+    );
+
+    // Emitted whenever tokens are added to the voucher pool
+    #[event("voucherPoolFunded")]
+    fn voucher_pool_funded_event(
+        &self,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin withdraws tokens from the voucher pool
+    #[event("voucherPoolWithdrawn")]
+    fn voucher_pool_withdrawn_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin withdraws the tokens held by the smart contract above the reserved amount
+    #[event("surplusWithdrawn")]
+    fn surplus_withdrawn_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin rescues tokens sent to the smart contract by mistake
+    #[event("tokensRescued")]
+    fn tokens_rescued_event(
+        &self,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever an address redeems a voucher
+    #[event("voucherRedeemed")]
+    fn voucher_redeemed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] nonce: u64,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin proposes a new admin
+    #[event("adminProposed")]
+    fn admin_proposed_event(
+        &self,
+        #[indexed] admin: &ManagedAddress,
+        #[indexed] pending_admin: &ManagedAddress,
+    );
+
+    // Emitted whenever the pending admin accepts the admin role
+    #[event("adminChanged")]
+    fn admin_changed_event(
+        &self,
+        #[indexed] previous_admin: &ManagedAddress,
+        #[indexed] admin: &ManagedAddress,
+    );
+
+    // Emitted whenever the timelock delay of the admin operations is changed
+    #[event("timelockDelaySet")]
+    fn timelock_delay_set_event(&self, #[indexed] delay: u64);
+
+    // Emitted whenever an admin operation is queued in the timelock
+    #[event("operationQueued")]
+    fn operation_queued_event(
+        &self,
+        #[indexed] operation_id: u64,
+        #[indexed] executable_from: u64,
+        operation: &AdminOperation<Self::Api>,
+    );
+
+    // Emitted whenever the admin executes an admin operation queued in the timelock
+    #[event("operationExecuted")]
+    fn operation_executed_event(&self, #[indexed] operation_id: u64);
+
+    // Emitted whenever a privileged address cancels an admin operation queued in the timelock
+    #[event("operationCancelled")]
+    fn operation_cancelled_event(
+        &self,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] operation_id: u64,
+    );
+
+    // Emitted whenever the admin sets the claim types in which a depositor can add claims
+    #[event("depositorClaimTypesSet")]
+    fn depositor_claim_types_set_event(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        claim_types: &ManagedVec<ClaimType>,
+    );
+
+    // Emitted whenever the admin sets the quota of a depositor for a token
+    #[event("depositorQuotaSet")]
+    fn depositor_quota_set_event(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] per_epoch: bool,
+    );
+
+    // Emitted whenever the admin clears the quota of a depositor for a token
+    #[event("depositorQuotaCleared")]
+    fn depositor_quota_cleared_event(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+    );
+
+    // Emitted whenever the admin sets the grace period during which the depositors can claw back the claims they added
+    #[event("clawbackGracePeriodSet")]
+    fn clawback_grace_period_set_event(&self, #[indexed] grace_period: u64);
+
+    // Emitted whenever a depositor claws back a claim it added
+    #[event("claimClawedBack")]
+    fn claim_clawed_back_event(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin sets the staking contract of a token
+    #[event("stakingContractSet")]
+    fn staking_contract_set_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] endpoint: &ManagedBuffer,
+    );
+
+    // Emitted whenever the admin clears the staking contract of a token
+    #[event("stakingContractCleared")]
+    fn staking_contract_cleared_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier);
+
+    // Emitted whenever the tokens harvested by an address are staked in the staking contract of the token
+    #[event("claimStaked")]
+    fn claim_staked_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] staking_contract: &ManagedAddress,
+    );
+
+    // Emitted whenever the staking call of harvested tokens fails and the tokens are restored to the claims of the address
+    #[event("claimStakeFailed")]
+    fn claim_stake_failed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin adds a function of a contract to the whitelist of targets of the claimAndExecute endpoint
+    #[event("executeTargetAdded")]
+    fn execute_target_added_event(
+        &self,
+        #[indexed] target: &ManagedAddress,
+        #[indexed] function: &ManagedBuffer,
+    );
+
+    // Emitted whenever the admin removes a function of a contract from the whitelist of targets of the claimAndExecute endpoint
+    #[event("executeTargetRemoved")]
+    fn execute_target_removed_event(
+        &self,
+        #[indexed] target: &ManagedAddress,
+        #[indexed] function: &ManagedBuffer,
+    );
+
+    // Emitted whenever the call of a whitelisted target contract with the tokens harvested by an address succeeds
+    #[event("claimExecuted")]
+    fn claim_executed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] target: &ManagedAddress,
+        #[indexed] function: &ManagedBuffer,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the call of a whitelisted target contract with harvested tokens fails and the tokens are restored to the claims of the address
+    #[event("claimExecuteFailed")]
+    fn claim_execute_failed_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] target: &ManagedAddress,
+        #[indexed] function: &ManagedBuffer,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the admin sets the number of latest movements kept in the claim history of each address
+    #[event("claimHistoryLimitSet")]
+    fn claim_history_limit_set_event(&self, #[indexed] limit: u64);
+
+    // Emitted whenever the admin creates a campaign
+    #[event("campaignCreated")]
+    fn campaign_created_event(
+        &self,
+        #[indexed] campaign_id: u64,
+        #[indexed] claim_type: &ClaimType,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] budget: &BigUint,
+        name: &ManagedBuffer,
+    );
+
+    // Emitted whenever a claim is added for a campaign, next to the claimAdded event of the claim
+    #[event("campaignClaimAdded")]
+    fn campaign_claim_added_event(
+        &self,
+        #[indexed] campaign_id: u64,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the part of a claim labeled with a campaign is harvested, next to the claimCollected event of the claim
+    #[event("campaignClaimCollected")]
+    fn campaign_claim_collected_event(
+        &self,
+        #[indexed] campaign_id: u64,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+    );
+
+    // Emitted whenever the part of a claim labeled with a campaign is removed, reclaimed or clawed back
+    #[event("campaignClaimRemoved")]
+    fn campaign_claim_removed_event(
+        &self,
+        #[indexed] campaign_id: u64,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: &BigUint,
     );
 }
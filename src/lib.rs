@@ -3,15 +3,28 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod admin;
+pub mod campaigns;
 pub mod constants;
+pub mod depositors;
+pub mod distribution;
 pub mod events;
+pub mod nfts;
 pub mod requirements;
+pub mod roles;
+pub mod staking;
 pub mod storage;
+pub mod targets;
 pub mod views;
+pub mod vouchers;
 
 use crate::{
     constants::*,
-    storage::{ClaimType, Len},
+    storage::{
+        AdminOperation, ClaimExpiry, ClaimFunding, ClaimKey, ClaimMovementKind, ClaimRemoval,
+        ClaimType, ClaimTypeInfo, ExecuteTarget, HarvestedClaim, RelayedClaim, Role,
+        VestingSchedule,
+    },
 };
 
 #[multiversx_sc::contract]
@@ -20,146 +33,405 @@ pub trait ClaimsContract:
     + events::EventsModule
     + views::ViewsModule
     + requirements::RequirementsModule
+    + distribution::DistributionModule
+    + vouchers::VouchersModule
+    + nfts::NftsModule
+    + admin::AdminModule
+    + roles::RolesModule
+    + depositors::DepositorsModule
+    + staking::StakingModule
+    + targets::TargetsModule
+    + campaigns::CampaignsModule
 {
-    // When the smart contract is deployed claim harvesting is paused
+    // When the smart contract is deployed claim harvesting is paused, the deployer becomes the admin and the default claim types are registered
     #[init]
     fn init(&self) {
         self.is_paused().set(true);
-    }
 
-    // Endpoint available for the owner of the smart contract to set the token used by the smart contract for claims. Can only be called once successfully.
-    #[only_owner]
-    #[endpoint(setClaimToken)]
-    fn set_claim_token(&self, token: TokenIdentifier) {
-        require!(self.claim_token().is_empty(), ERR_TOKEN_SET);
-        self.claim_token().set(&token);
+        if self.admin().is_empty() {
+            self.admin().set(self.blockchain().get_caller());
+        }
+
+        // The privileged and depositor addresses set before roles were introduced keep their rights through the corresponding roles
+        for address in self.privileged_addresses().iter() {
+            self.role_members(&Role::Pauser).insert(address.clone());
+            self.role_members(&Role::Depositor).insert(address);
+        }
+        self.privileged_addresses().clear();
+        for address in self.depositor_addresses().iter() {
+            self.role_members(&Role::Depositor).insert(address);
+        }
+        self.depositor_addresses().clear();
+
+        // The token used for claims before several tokens could be whitelisted is whitelisted once, so its legacy claims can be managed like the other claims.
+        // Its balance, which was entirely reserved for the legacy claims, is added to its total reserved
+        if !self.claim_token().is_empty() && !self.claim_token_migrated().get() {
+            let legacy_token = EgldOrEsdtTokenIdentifier::esdt(self.claim_token().get());
+            let balance = self.blockchain().get_sc_balance(&legacy_token, 0);
+            self.total_reserved(&legacy_token)
+                .update(|total| *total += balance);
+            self.claim_tokens().insert(legacy_token);
+            self.claim_token_migrated().set(true);
+        }
+
+        // The default claim types keep the identifiers they had before claim types could be registered, which the legacy claims are stored under
+        for (claim_type, name) in DEFAULT_CLAIM_TYPES {
+            if !self.claim_types().contains_key(&claim_type) {
+                self.claim_types().insert(
+                    claim_type,
+                    ClaimTypeInfo {
+                        name: ManagedBuffer::from(name),
+                        enabled: true,
+                    },
+                );
+            }
+        }
     }
 
-    // Endpoint available for privileged addresses of the smart contract to pause claim harvesting. Cannot be called while harvesting is already paused.
-    #[endpoint(pause)]
-    fn pause(&self) {
-        require!(!self.is_paused().get(), ERR_CONTRACT_ALREADY_PAUSED);
+    // Endpoint available for the admin of the smart contract to add a token to the whitelist of tokens that can be used for claims. EGLD can be whitelisted as well.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(addClaimToken)]
+    fn add_claim_token(&self, token: EgldOrEsdtTokenIdentifier) {
         let caller = self.blockchain().get_caller();
-        self.require_address_is_privileged(&caller);
-        self.is_paused().set(true);
-        self.harvest_paused_event(&caller);
+        self.require_address_is_admin(&caller);
+        require!(token.is_valid(), ERR_TOKEN_INVALID);
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::AddClaimToken { token });
+            return;
+        }
+        self.execute_add_claim_token(token);
     }
 
-    // Endpoint available for the owner of the smart contract to resume claim harvesting. Cannot be called while harvesting is already unpaused.
-    #[only_owner]
-    #[endpoint(unpause)]
-    fn unpause(&self) {
-        require!(self.is_paused().get(), ERR_CONTRACT_ALREADY_UNPAUSED);
-        self.is_paused().set(false);
-        self.harvest_unpaused_event();
+    // Endpoint available for the admin of the smart contract to remove a token from the whitelist of tokens that can be used for claims.
+    // Claims already reserved in the token can still be harvested by specifying the token when harvesting.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(removeClaimToken)]
+    fn remove_claim_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::RemoveClaimToken { token });
+            return;
+        }
+        self.execute_remove_claim_token(token);
     }
 
-    // Endpoint available for owner in order to add an address to the list of privileged addresses
-    #[only_owner]
-    #[endpoint(addPrivilegedAddress)]
-    fn add_privileged_address(&self, address: ManagedAddress) {
-        let mut privileged_addresses = self.privileged_addresses();
+    // Endpoint available for the admin of the smart contract to register a new claim type, which is enabled when registered.
+    #[endpoint(addClaimType)]
+    fn add_claim_type(&self, claim_type: ClaimType, name: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        let mut claim_types = self.claim_types();
         require!(
-            !privileged_addresses.contains(&address),
-            ERR_ADDRESS_PRIVILEGED
+            !claim_types.contains_key(&claim_type),
+            ERR_CLAIM_TYPE_REGISTERED
         );
         require!(
-            privileged_addresses.len() < MAX_NUMBER_OF_PRIVILEGED_ADDRESSES,
-            ERR_MAX_NUMBER_OF_PRIVILEGED_ADDRESSES
+            claim_types.len() < MAX_NUMBER_OF_CLAIM_TYPES,
+            ERR_MAX_NUMBER_OF_CLAIM_TYPES
+        );
+        claim_types.insert(
+            claim_type,
+            ClaimTypeInfo {
+                name: name.clone(),
+                enabled: true,
+            },
         );
+        self.claim_type_added_event(&claim_type, &name);
+    }
+
+    // Endpoint available for the admin of the smart contract to index the claims of a bulk of addresses in the claimant registry, in order to include the claims left unchanged since before the registry was introduced.
+    // The legacy claims of the addresses are moved to the claim storage, even if the legacy claim token is no longer whitelisted.
+    #[endpoint(indexClaimants)]
+    fn index_claimants(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.require_number_of_claims_in_bulk_is_valid(&addresses.len());
 
-        let owner = self.blockchain().get_owner_address();
-        require!(owner != address, ERR_OWNER_NOT_PRIVILEGED);
+        let mut tokens = ManagedVec::<Self::Api, EgldOrEsdtTokenIdentifier>::new();
+        for token in self.claim_tokens().iter() {
+            tokens.push(token);
+        }
+        if !self.claim_token().is_empty() {
+            let legacy_token = EgldOrEsdtTokenIdentifier::esdt(self.claim_token().get());
+            if !self.claim_tokens().contains(&legacy_token) {
+                tokens.push(legacy_token);
+            }
+        }
 
-        self.privileged_address_added_event(&address);
-        privileged_addresses.insert(address);
+        for address in addresses.into_iter() {
+            for claim_type in self.claim_types().keys() {
+                for token in tokens.iter() {
+                    let claim = self.get_claim(&address, &claim_type, &token);
+                    self.set_claim(&address, &claim_type, &token, claim);
+                }
+                for nft_nonce in self.nft_claims(&address, &claim_type).keys() {
+                    self.register_claimant_claim(
+                        &address,
+                        ClaimKey {
+                            claim_type,
+                            token: EgldOrEsdtTokenIdentifier::esdt(nft_nonce.token),
+                            nonce: nft_nonce.nonce,
+                        },
+                    );
+                }
+            }
+        }
     }
 
-    // Endpoint available for owner in order to remove an address from the list of privileged addresses
-    #[only_owner]
-    #[endpoint(removePrivilegedAddress)]
-    fn remove_privileged_address(&self, address: ManagedAddress) {
-        let mut privileged_addresses = self.privileged_addresses();
+    // Endpoint available for the admin of the smart contract to set the number of latest movements kept in the claim history of each address. Setting it to zero stops recording the claim history.
+    #[endpoint(setClaimHistoryLimit)]
+    fn set_claim_history_limit(&self, limit: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
         require!(
-            privileged_addresses.contains(&address),
-            ERR_ADDRESS_NOT_PRIVILEGED
+            limit <= MAX_CLAIM_HISTORY_LIMIT,
+            ERR_CLAIM_HISTORY_LIMIT_TOO_HIGH
         );
+        self.claim_history_limit().set(limit);
+        self.claim_history_limit_set_event(limit);
+    }
+
+    // Endpoint available for the admin of the smart contract to enable or disable a registered claim type. No new claims can be added for a disabled claim type, but its existing claims can still be harvested.
+    #[endpoint(setClaimTypeEnabled)]
+    fn set_claim_type_enabled(&self, claim_type: ClaimType, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        let mut claim_types = self.claim_types();
+        let claim_type_info = claim_types.get(&claim_type);
+        require!(claim_type_info.is_some(), ERR_CLAIM_TYPE_NOT_REGISTERED);
+
+        let mut claim_type_info = claim_type_info.unwrap();
+        if enabled {
+            require!(!claim_type_info.enabled, ERR_CLAIM_TYPE_ALREADY_ENABLED);
+        } else {
+            require!(claim_type_info.enabled, ERR_CLAIM_TYPE_ALREADY_DISABLED);
+        }
+        claim_type_info.enabled = enabled;
+        claim_types.insert(claim_type, claim_type_info);
+        self.claim_type_enabled_set_event(&claim_type, enabled);
+    }
+
+    // Endpoint available for the admin of the smart contract to withdraw the tokens it holds above the total amount reserved for claims, distributions and voucher pools, such as tokens sent to it by mistake.
+    #[endpoint(withdrawSurplus)]
+    fn withdraw_surplus(&self, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        let balance = self.blockchain().get_sc_balance(&token, 0);
+        let reserved = self.total_reserved(&token).get();
+        require!(balance > reserved, ERR_NO_SURPLUS);
+
+        let surplus = balance - reserved;
+        self.surplus_withdrawn_event(&token, &surplus);
 
-        self.privileged_address_removed_event(&address);
-        privileged_addresses.remove(&address);
+        let admin = self.admin().get();
+        self.send().direct(&admin, &token, 0, &surplus);
     }
 
-    // Endpoint available for owner in order to add depositor addresses
-    #[only_owner]
-    #[endpoint(addDepositorAddress)]
-    fn add_depositor_address(&self, address: ManagedAddress) {
-        let mut depositor_addresses = self.depositor_addresses();
-        require!(
-            !depositor_addresses.contains(&address),
-            ERR_ADDRESS_DEPOSITOR
-        );
+    // Endpoint available for the admin of the smart contract to rescue tokens sent to it by mistake. Only the surplus above the amount reserved for claims, distributions and voucher pools can be rescued, whether or not the token is whitelisted.
+    // The NFTs and SFTs can only be rescued in the quantity of each nonce that is not reserved by NFT claims.
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens(&self, token: EgldOrEsdtTokenIdentifier, nonce: u64, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.require_value_not_zero(&amount);
+        require!(token.is_esdt() || nonce == 0, ERR_EGLD_NONCE_NOT_ZERO);
 
-        let owner = self.blockchain().get_owner_address();
-        require!(owner != address, ERR_OWNER_NOT_DEPOSITOR);
+        let balance = self.blockchain().get_sc_balance(&token, nonce);
+        if nonce == 0 {
+            let reserved = self.total_reserved(&token).get();
+            require!(balance >= reserved + &amount, ERR_RESCUE_MORE_THAN_SURPLUS);
+        } else {
+            let reserved = self.nft_reserved(&token.clone().unwrap_esdt(), nonce).get();
+            require!(balance >= reserved + &amount, ERR_RESCUE_RESERVED_NFT);
+        }
 
-        self.depositor_address_added_event(&address);
-        depositor_addresses.insert(address);
+        let admin = self.admin().get();
+        self.tokens_rescued_event(&admin, &token, nonce, &amount);
+        self.send().direct(&admin, &token, nonce, &amount);
     }
 
-    // Endpoint available for owner in order to remove depositor addresses
-    #[only_owner]
-    #[endpoint(removeDepositorAddress)]
-    fn remove_depositor_address(&self, address: ManagedAddress) {
-        let mut depositor_addresses = self.depositor_addresses();
+    // Endpoint available for privileged addresses of the smart contract to pause claim harvesting. Cannot be called while harvesting is already paused.
+    #[endpoint(pause)]
+    fn pause(&self) {
+        require!(!self.is_paused().get(), ERR_CONTRACT_ALREADY_PAUSED);
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_privileged(&caller);
+        self.is_paused().set(true);
+        self.harvest_paused_event(&caller);
+    }
+
+    // Endpoint available for the admin of the smart contract and the addresses with the Unpauser role to resume claim harvesting. Cannot be called while harvesting is already unpaused.
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_role(&Role::Unpauser, &caller);
+        require!(self.is_paused().get(), ERR_CONTRACT_ALREADY_UNPAUSED);
+        self.is_paused().set(false);
+        self.harvest_unpaused_event();
+    }
+
+    // Endpoint available for privileged addresses of the smart contract to pause the harvesting of a claim type, while the other claim types can still be harvested. Cannot be called while the claim type is already paused.
+    #[endpoint(pauseClaimType)]
+    fn pause_claim_type(&self, claim_type: ClaimType) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_privileged(&caller);
+        self.require_claim_type_is_registered(&claim_type);
         require!(
-            depositor_addresses.contains(&address),
-            ERR_ADDRESS_NOT_DEPOSITOR
+            self.paused_claim_types().insert(claim_type),
+            ERR_CLAIM_TYPE_ALREADY_PAUSED
         );
+        self.claim_type_paused_event(&caller, &claim_type);
+    }
 
-        self.depositor_address_removed_event(&address);
-        depositor_addresses.remove(&address);
+    // Endpoint available for the admin of the smart contract and the addresses with the Unpauser role to resume the harvesting of a claim type. Cannot be called while the claim type is not paused.
+    #[endpoint(unpauseClaimType)]
+    fn unpause_claim_type(&self, claim_type: ClaimType) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_role(&Role::Unpauser, &caller);
+        require!(
+            self.paused_claim_types().swap_remove(&claim_type),
+            ERR_CLAIM_TYPE_ALREADY_UNPAUSED
+        );
+        self.claim_type_unpaused_event(&claim_type);
     }
 
-    // Endpoint available for privileged addresses of the smart contract to add a claim of a specific claim type for a specific address.
+    // Endpoint available for privileged addresses of the smart contract to add a claim of a specific claim type for a specific address, in any of the whitelisted tokens.
+    // An optional expiry timestamp can be given, after which the claim can no longer be harvested and its tokens can be returned to the depositor.
     #[payable("*")]
     #[endpoint(addClaim)]
-    fn add_claim(&self, address: &ManagedAddress, claim_type: ClaimType) {
-        self.require_claim_token_is_set();
-
-        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
-        self.require_token_is_correct(payment_token);
+    fn add_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: ClaimType,
+        expiry: OptionalValue<u64>,
+    ) {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_token_is_whitelisted(&payment_token);
         self.require_value_not_zero(&payment_amount);
 
         let caller = self.blockchain().get_caller();
         self.require_address_has_deposit_rights(&caller);
+        self.require_claim_type_is_enabled(&claim_type);
+        self.require_depositor_can_add_claim_type(&caller, &claim_type);
+        self.require_claim_not_expired(address, &claim_type);
+        self.require_claim_expiry_allows_depositor(address, &claim_type, &caller);
+        self.use_depositor_quota(&caller, &payment_token, &payment_amount);
+
+        if let OptionalValue::Some(expiry) = expiry {
+            self.require_expiry_is_valid(expiry);
+            self.set_claim_expiry(address, &claim_type, expiry, &caller);
+        }
 
         //Add the amount of the tokens sent to the current claim reservation
-        let current_claim = self.claim(address, &claim_type).get();
-        self.claim(address, &claim_type)
-            .set(current_claim + &payment_amount);
+        let current_claim = self.get_claim(address, &claim_type, &payment_token);
+        self.set_claim(
+            address,
+            &claim_type,
+            &payment_token,
+            current_claim + &payment_amount,
+        );
+        self.increase_reserved(Some(&claim_type), &payment_token, &payment_amount);
+        self.add_claim_funding(
+            address,
+            &claim_type,
+            &payment_token,
+            &caller,
+            &payment_amount,
+        );
 
         //Update the last modification date of the claim to the current timestamp
         let timestamp = self.blockchain().get_block_timestamp();
         self.claim_modify_date(address, &claim_type).set(timestamp);
-        self.claim_added_event(&caller, &address, &claim_type, &payment_amount);
+        self.record_claim_movement(
+            address,
+            &claim_type,
+            &payment_token,
+            &payment_amount,
+            ClaimMovementKind::Added,
+        );
+        self.claim_added_event(
+            &caller,
+            address,
+            &claim_type,
+            &payment_token,
+            &payment_amount,
+        );
     }
 
-    // Endpoint available for privileged addresses of the smart contract to add a bulk of claims of different claim types for different specific addresses.
+    // Endpoint available for privileged addresses of the smart contract to add a bulk of claims of different claim types for different specific addresses. All the claims are added in the token sent.
     #[payable("*")]
     #[endpoint(addClaims)]
     fn add_claims(
         &self,
         claims: MultiValueEncoded<MultiValue3<ManagedAddress, ClaimType, BigUint>>,
     ) {
-        self.require_claim_token_is_set();
+        self.add_claims_with_optional_expiry(claims, None, None);
+    }
+
+    // Endpoint available for privileged addresses of the smart contract to add a bulk of claims which all expire at the given timestamp.
+    #[payable("*")]
+    #[endpoint(addClaimsWithExpiry)]
+    fn add_claims_with_expiry(
+        &self,
+        expiry: u64,
+        claims: MultiValueEncoded<MultiValue3<ManagedAddress, ClaimType, BigUint>>,
+    ) {
+        self.require_expiry_is_valid(expiry);
+        self.add_claims_with_optional_expiry(claims, Some(expiry), None);
+    }
+
+    // Endpoint available for privileged addresses of the smart contract to add a claim for a campaign, similar to the addClaim endpoint. The claim type and the token sent must be the ones of the campaign and the total added to the campaign cannot exceed its budget.
+    #[payable("*")]
+    #[endpoint(addCampaignClaim)]
+    fn add_campaign_claim(
+        &self,
+        campaign_id: u64,
+        address: ManagedAddress,
+        claim_type: ClaimType,
+        expiry: OptionalValue<u64>,
+    ) {
+        let expiry = expiry.into_option();
+        if let Some(expiry) = expiry {
+            self.require_expiry_is_valid(expiry);
+        }
+
+        let payment_amount = self.call_value().egld_or_single_fungible_esdt().1;
+        let mut claims = MultiValueEncoded::new();
+        claims.push(MultiValue3::from((address, claim_type, payment_amount)));
+        self.add_claims_with_optional_expiry(claims, expiry, Some(campaign_id));
+    }
+
+    // Endpoint available for privileged addresses of the smart contract to add a bulk of claims for a campaign, similar to the addClaims endpoint. The claim types and the token sent must be the ones of the campaign and the total added to the campaign cannot exceed its budget.
+    #[payable("*")]
+    #[endpoint(addCampaignClaims)]
+    fn add_campaign_claims(
+        &self,
+        campaign_id: u64,
+        claims: MultiValueEncoded<MultiValue3<ManagedAddress, ClaimType, BigUint>>,
+    ) {
+        self.add_claims_with_optional_expiry(claims, None, Some(campaign_id));
+    }
+
+    fn add_claims_with_optional_expiry(
+        &self,
+        claims: MultiValueEncoded<MultiValue3<ManagedAddress, ClaimType, BigUint>>,
+        expiry: Option<u64>,
+        campaign_id: Option<u64>,
+    ) {
         self.require_number_of_claims_in_bulk_is_valid(&claims.len());
 
-        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
-        self.require_token_is_correct(payment_token);
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_token_is_whitelisted(&payment_token);
         self.require_value_not_zero(&payment_amount);
+        let campaign = campaign_id
+            .map(|campaign_id| self.require_campaign_accepts_token(campaign_id, &payment_token));
 
         let caller = self.blockchain().get_caller();
         self.require_address_has_deposit_rights(&caller);
+        self.use_depositor_quota(&caller, &payment_token, &payment_amount);
 
         let timestamp = self.blockchain().get_block_timestamp();
         // Initialize the sum of claims to be added to zero
@@ -168,117 +440,978 @@ pub trait ClaimsContract:
         for item in claims.into_iter() {
             let (address, claim_type, amount) = item.into_tuple();
             self.require_value_not_zero(&amount);
+            if let Some(campaign) = &campaign {
+                require!(
+                    claim_type == campaign.claim_type,
+                    ERR_CAMPAIGN_CLAIM_TYPE_MISMATCH
+                );
+            }
+            self.require_claim_type_is_enabled(&claim_type);
+            self.require_depositor_can_add_claim_type(&caller, &claim_type);
+            self.require_claim_not_expired(&address, &claim_type);
+            self.require_claim_expiry_allows_depositor(&address, &claim_type, &caller);
+
+            if let Some(expiry) = expiry {
+                self.set_claim_expiry(&address, &claim_type, expiry, &caller);
+            }
 
-            let current_claim = self.claim(&address, &claim_type).get();
-            self.claim(&address, &claim_type)
-                .set(current_claim + &amount);
+            let current_claim = self.get_claim(&address, &claim_type, &payment_token);
+            self.set_claim(
+                &address,
+                &claim_type,
+                &payment_token,
+                current_claim + &amount,
+            );
+            self.increase_reserved(Some(&claim_type), &payment_token, &amount);
+            self.add_claim_funding(&address, &claim_type, &payment_token, &caller, &amount);
+            if let Some(campaign_id) = campaign_id {
+                self.add_claim_campaign(
+                    &address,
+                    &claim_type,
+                    &payment_token,
+                    campaign_id,
+                    &amount,
+                );
+            }
             self.claim_modify_date(&address, &claim_type).set(timestamp);
             sum_of_claims += &amount;
-            self.claim_added_event(&caller, &address, &claim_type, &amount);
+            self.record_claim_movement(
+                &address,
+                &claim_type,
+                &payment_token,
+                &amount,
+                ClaimMovementKind::Added,
+            );
+            self.claim_added_event(&caller, &address, &claim_type, &payment_token, &amount);
         }
 
         // Panic if the amount of tokens sent by the owner to the endpoint are not equal to the sum of the claims added to the contract
         require!(sum_of_claims == payment_amount, ERR_CLAIM_EQUAL_PAYMENT);
+        if let Some(campaign_id) = campaign_id {
+            self.deposit_to_campaign(campaign_id, &payment_amount);
+        }
     }
 
-    // Endpoint available for the owner of the smart contract to remove a claim of a specific claim type for a specific address.
-    #[only_owner]
-    #[endpoint(removeClaim)]
-    fn remove_claim(&self, address: &ManagedAddress, claim_type: ClaimType, amount: BigUint) {
-        self.require_claim_token_is_set();
-        self.require_value_not_zero(&amount);
+    // Endpoint available for privileged addresses of the smart contract to add a claim of a specific claim type for a specific address which unlocks linearly between the start and end timestamps.
+    // An optional cliff timestamp can be given, before which none of the tokens are unlocked. A claim can only have one active vesting schedule at a time for each token.
+    #[payable("*")]
+    #[endpoint(addVestedClaim)]
+    fn add_vested_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: ClaimType,
+        start: u64,
+        end: u64,
+        cliff: OptionalValue<u64>,
+    ) {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_token_is_whitelisted(&payment_token);
+        self.require_value_not_zero(&payment_amount);
 
-        let current_claim = self.claim(address, &claim_type).get();
-        self.require_remove_claim_is_valid(&current_claim, &amount);
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_deposit_rights(&caller);
 
-        // Remove the amount of tokens given as argument from the current claim reservation
-        self.claim(address, &claim_type)
-            .set(current_claim - &amount);
+        self.require_claim_type_is_enabled(&claim_type);
+        self.require_depositor_can_add_claim_type(&caller, &claim_type);
+        self.require_claim_not_expired(address, &claim_type);
+        self.require_claim_expiry_allows_depositor(address, &claim_type, &caller);
+        self.use_depositor_quota(&caller, &payment_token, &payment_amount);
+
+        let cliff = cliff.into_option().unwrap_or(start);
+        self.require_vesting_schedule_is_valid(start, cliff, end);
+        require!(
+            self.view_locked_claim(address, &claim_type, &payment_token) == BigUint::zero(),
+            ERR_VESTING_SCHEDULE_ACTIVE
+        );
+
+        // Replace the previous (fully unlocked) schedule, the amount already unlocked from it stays in the claim
+        self.vesting_schedule(address, &claim_type, &payment_token)
+            .set(VestingSchedule {
+                amount: payment_amount.clone(),
+                start,
+                cliff,
+                end,
+            });
+
+        let current_claim = self.get_claim(address, &claim_type, &payment_token);
+        self.set_claim(
+            address,
+            &claim_type,
+            &payment_token,
+            current_claim + &payment_amount,
+        );
+        self.increase_reserved(Some(&claim_type), &payment_token, &payment_amount);
+        self.add_claim_funding(
+            address,
+            &claim_type,
+            &payment_token,
+            &caller,
+            &payment_amount,
+        );
 
-        // Update the modification date of the claim to the current timestamp
         let timestamp = self.blockchain().get_block_timestamp();
         self.claim_modify_date(address, &claim_type).set(timestamp);
-        self.claim_removed_event(&address, &claim_type, &amount);
+        self.record_claim_movement(
+            address,
+            &claim_type,
+            &payment_token,
+            &payment_amount,
+            ClaimMovementKind::Added,
+        );
+        self.claim_added_event(
+            &caller,
+            address,
+            &claim_type,
+            &payment_token,
+            &payment_amount,
+        );
+        self.vesting_schedule_added_event(
+            address,
+            &claim_type,
+            &payment_token,
+            &payment_amount,
+            start,
+            cliff,
+            end,
+        );
+    }
+
+    // Endpoint available for the admin of the smart contract and the addresses with the ClaimRemover role to remove a claim of a specific claim type and token for a specific address. Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(removeClaim)]
+    fn remove_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: ClaimType,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_role(&Role::ClaimRemover, &caller);
+        self.require_value_not_zero(&amount);
 
-        // Send the removed tokens from the claim back to the owner of the smart contract
-        let owner = self.blockchain().get_owner_address();
-        let claim_token = self.claim_token().get();
-        self.send().direct_esdt(&owner, &claim_token, 0, &amount);
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::RemoveClaim {
+                address: address.clone(),
+                claim_type,
+                token,
+                amount,
+            });
+            return;
+        }
+        self.execute_remove_claim(address, claim_type, token, amount);
     }
 
-    // Endpoint available for the owner of the smart contract to remove a bulk of claims of different claim types for different specific addresses.
-    #[only_owner]
+    // Endpoint available for the admin of the smart contract and the addresses with the ClaimRemover role to remove a bulk of claims of different claim types for different specific addresses. All the claims are removed from the given token.
+    // Queued in the timelock whenever a timelock delay is set.
     #[endpoint(removeClaims)]
     fn remove_claims(
         &self,
+        token: EgldOrEsdtTokenIdentifier,
         claims: MultiValueEncoded<MultiValue3<ManagedAddress, ClaimType, BigUint>>,
     ) {
-        self.require_claim_token_is_set();
-
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_role(&Role::ClaimRemover, &caller);
         // Panics if the user tries to add more than 200 claims per operation.
         self.require_number_of_claims_in_bulk_is_valid(&claims.len());
 
-        // Initialize the sum of claims to be removed to zero
-        let mut sum_of_claims = BigUint::zero();
-
-        let timestamp = self.blockchain().get_block_timestamp();
-        // Iterate over the claims provided as argument and proceeds similarly to the remove_claim endpoint for each one
+        let mut claim_removals = ManagedVec::new();
         for item in claims.into_iter() {
             let (address, claim_type, amount) = item.into_tuple();
             self.require_value_not_zero(&amount);
+            claim_removals.push(ClaimRemoval {
+                address,
+                claim_type,
+                amount,
+            });
+        }
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::RemoveClaims {
+                token,
+                claims: claim_removals,
+            });
+            return;
+        }
+        self.execute_remove_claims(token, claim_removals);
+    }
+
+    // Endpoint available for the admin of the smart contract to execute an admin operation queued in the timelock once the timelock delay has passed.
+    #[endpoint(executeOperation)]
+    fn execute_operation(&self, operation_id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+
+        let queued_operation = self.queued_operations().get(&operation_id);
+        require!(queued_operation.is_some(), ERR_OPERATION_NOT_FOUND);
+        let queued_operation = queued_operation.unwrap();
+        require!(
+            queued_operation.executable_from <= self.blockchain().get_block_timestamp(),
+            ERR_OPERATION_NOT_READY
+        );
+
+        self.queued_operations().remove(&operation_id);
+        self.operation_executed_event(operation_id);
+
+        match queued_operation.operation {
+            AdminOperation::RemoveClaim {
+                address,
+                claim_type,
+                token,
+                amount,
+            } => self.execute_remove_claim(&address, claim_type, token, amount),
+            AdminOperation::RemoveClaims { token, claims } => {
+                self.execute_remove_claims(token, claims)
+            }
+            AdminOperation::RevokeRole { role, address } => self.execute_revoke_role(role, address),
+            AdminOperation::AddClaimToken { token } => self.execute_add_claim_token(token),
+            AdminOperation::RemoveClaimToken { token } => self.execute_remove_claim_token(token),
+            AdminOperation::SetTimelockDelay { delay } => {
+                self.timelock_delay().set(delay);
+                self.timelock_delay_set_event(delay);
+            }
+            AdminOperation::RemoveNftClaim {
+                address,
+                claim_type,
+                token,
+                nonce,
+                amount,
+            } => self.execute_remove_nft_claim(&address, claim_type, token, nonce, amount),
+            AdminOperation::WithdrawVoucherPool { token, amount } => {
+                self.execute_withdraw_voucher_pool(token, amount)
+            }
+            AdminOperation::SetVoucherSigner { signer } => self.execute_set_voucher_signer(signer),
+            AdminOperation::SetStakingContract {
+                token,
+                address,
+                endpoint,
+            } => self.execute_set_staking_contract(token, address, endpoint),
+            AdminOperation::AddExecuteTarget { address, function } => {
+                self.execute_add_execute_target(address, function)
+            }
+        }
+    }
+
+    // Adds a token to the whitelist of claim tokens, once the admin operation is executed
+    fn execute_add_claim_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        require!(
+            self.claim_tokens().insert(token.clone()),
+            ERR_TOKEN_WHITELISTED
+        );
+        self.claim_token_added_event(&token);
+    }
+
+    // Removes a token from the whitelist of claim tokens, once the admin operation is executed
+    fn execute_remove_claim_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        require!(
+            self.claim_tokens().swap_remove(&token),
+            ERR_TOKEN_NOT_WHITELISTED
+        );
+        self.claim_token_removed_event(&token);
+    }
+
+    // Removes a claim and refunds its tokens to the depositors that funded it, proportionally to their funding, once the admin operation is executed
+    fn execute_remove_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: ClaimType,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let current_claim = self.get_claim(address, &claim_type, &token);
+        self.require_remove_claim_is_valid(&current_claim, &amount);
+
+        // Remove the amount of tokens given as argument from the current claim reservation
+        let refunds =
+            self.take_claim_funding(address, &claim_type, &token, &current_claim, &amount);
+        self.take_claim_campaigns(
+            address,
+            &claim_type,
+            &token,
+            &current_claim,
+            &amount,
+            ClaimMovementKind::Removed,
+        );
+        self.set_claim(address, &claim_type, &token, current_claim - &amount);
+        self.decrease_reserved(Some(&claim_type), &token, &amount);
 
-            let current_claim = self.claim(&address, &claim_type).get();
+        // Update the modification date of the claim to the current timestamp
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.claim_modify_date(address, &claim_type).set(timestamp);
+        self.record_claim_movement(
+            address,
+            &claim_type,
+            &token,
+            &amount,
+            ClaimMovementKind::Removed,
+        );
+
+        // Refund the removed tokens from the claim to the depositors that funded it
+        for refund in refunds.iter() {
+            if refund.amount == BigUint::zero() {
+                continue;
+            }
+            self.claim_removed_event(
+                address,
+                &claim_type,
+                &token,
+                &refund.amount,
+                &refund.depositor,
+            );
+            self.send()
+                .direct(&refund.depositor, &token, 0, &refund.amount);
+        }
+    }
+
+    // Removes a bulk of claims and refunds their tokens to the depositors that funded them, once the admin operation is executed
+    fn execute_remove_claims(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        claims: ManagedVec<ClaimRemoval<Self::Api>>,
+    ) {
+        // Aggregate the refunds of all the claims for each depositor
+        let mut refunds = ManagedVec::<Self::Api, ClaimFunding<Self::Api>>::new();
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        // Iterate over the claims provided as argument and proceeds similarly to the remove_claim endpoint for each one
+        for claim_removal in claims.iter() {
+            let ClaimRemoval {
+                address,
+                claim_type,
+                amount,
+            } = claim_removal;
+
+            let current_claim = self.get_claim(&address, &claim_type, &token);
             self.require_remove_claim_is_valid(&current_claim, &amount);
 
             self.claim_modify_date(&address, &claim_type).set(timestamp);
-            sum_of_claims += &amount;
-            self.claim(&address, &claim_type)
-                .set(current_claim - &amount);
-            self.claim_removed_event(&address, &claim_type, &amount);
+            let claim_refunds =
+                self.take_claim_funding(&address, &claim_type, &token, &current_claim, &amount);
+            self.take_claim_campaigns(
+                &address,
+                &claim_type,
+                &token,
+                &current_claim,
+                &amount,
+                ClaimMovementKind::Removed,
+            );
+            self.set_claim(&address, &claim_type, &token, current_claim - &amount);
+            self.decrease_reserved(Some(&claim_type), &token, &amount);
+            self.record_claim_movement(
+                &address,
+                &claim_type,
+                &token,
+                &amount,
+                ClaimMovementKind::Removed,
+            );
+
+            for refund in claim_refunds.iter() {
+                if refund.amount == BigUint::zero() {
+                    continue;
+                }
+                self.claim_removed_event(
+                    &address,
+                    &claim_type,
+                    &token,
+                    &refund.amount,
+                    &refund.depositor,
+                );
+
+                match refunds
+                    .iter()
+                    .position(|refunded| refunded.depositor == refund.depositor)
+                {
+                    Some(index) => {
+                        let mut refunded = refunds.get(index);
+                        refunded.amount += &refund.amount;
+                        let _ = refunds.set(index, &refunded);
+                    }
+                    None => refunds.push(refund),
+                }
+            }
+        }
+
+        // Refund the removed tokens from the claims to the depositors that funded them
+        for refund in refunds.iter() {
+            self.send()
+                .direct(&refund.depositor, &token, 0, &refund.amount);
+        }
+    }
+
+    // Endpoint available for the admin of the smart contract and the depositors that set the expiry of expired claims to return the tokens of a bulk of expired claims to their depositors.
+    // The tokens of an expired claim are returned in all its tokens, whether or not they are still whitelisted, to the depositors that funded them, proportionally to their funding, together with its NFTs and SFTs.
+    #[endpoint(reclaimExpiredClaims)]
+    fn reclaim_expired_claims(
+        &self,
+        claims: MultiValueEncoded<MultiValue2<ManagedAddress, ClaimType>>,
+    ) {
+        self.require_number_of_claims_in_bulk_is_valid(&claims.len());
+
+        let caller = self.blockchain().get_caller();
+        let admin = self.admin().get();
+        let timestamp = self.blockchain().get_block_timestamp();
+        for item in claims.into_iter() {
+            let (address, claim_type) = item.into_tuple();
+            require!(
+                self.is_claim_expired(&address, &claim_type),
+                ERR_CLAIM_NOT_EXPIRED
+            );
+
+            let depositor = self.claim_expiry(&address, &claim_type).get().depositor;
+            require!(
+                caller == admin || caller == depositor,
+                ERR_ADDRESS_NOT_AUTHORIZED
+            );
+
+            self.claim_expiry(&address, &claim_type).clear();
+            self.claim_modify_date(&address, &claim_type).set(timestamp);
+
+            // The whole claim is returned, including the tokens still locked by its vesting schedule
+            for token in self.claim_type_tokens(&address, &claim_type).iter() {
+                let amount = self.get_claim(&address, &claim_type, &token);
+                if amount == BigUint::zero() {
+                    continue;
+                }
+
+                self.set_claim(&address, &claim_type, &token, BigUint::zero());
+                self.clear_claim_deposits(&address, &claim_type, &token);
+                let refunds =
+                    self.take_claim_funding(&address, &claim_type, &token, &amount, &amount);
+                self.take_claim_campaigns(
+                    &address,
+                    &claim_type,
+                    &token,
+                    &amount,
+                    &amount,
+                    ClaimMovementKind::Removed,
+                );
+                self.decrease_reserved(Some(&claim_type), &token, &amount);
+                self.record_claim_movement(
+                    &address,
+                    &claim_type,
+                    &token,
+                    &amount,
+                    ClaimMovementKind::Removed,
+                );
+
+                for refund in refunds.iter() {
+                    if refund.amount == BigUint::zero() {
+                        continue;
+                    }
+                    self.claim_expired_event(
+                        &address,
+                        &claim_type,
+                        &refund.depositor,
+                        &token,
+                        &refund.amount,
+                    );
+                    self.send()
+                        .direct(&refund.depositor, &token, 0, &refund.amount);
+                }
+            }
+            self.reclaim_expired_nft_claims(&address, &claim_type);
+        }
+    }
+
+    // Endpoint available for the public to set the address to which their claims are sent when harvesting. Can be given a claim type to set the beneficiary only for that claim type, which takes precedence over the beneficiary set for all claim types.
+    #[endpoint(setBeneficiary)]
+    fn set_beneficiary(&self, beneficiary: ManagedAddress, claim_type: OptionalValue<ClaimType>) {
+        let caller = self.blockchain().get_caller();
+        let claim_type = claim_type.into_option();
+        match &claim_type {
+            Some(claim_type) => {
+                self.require_claim_type_is_registered(claim_type);
+                self.claim_type_beneficiary(&caller, claim_type)
+                    .set(&beneficiary);
+            }
+            None => self.beneficiary(&caller).set(&beneficiary),
+        }
+        self.beneficiary_set_event(&caller, &claim_type, &beneficiary);
+    }
+
+    // Endpoint available for the public to clear the beneficiary set for all claim types or for the given claim type, so that their claims are sent to them again.
+    #[endpoint(clearBeneficiary)]
+    fn clear_beneficiary(&self, claim_type: OptionalValue<ClaimType>) {
+        let caller = self.blockchain().get_caller();
+        let claim_type = claim_type.into_option();
+        match &claim_type {
+            Some(claim_type) => self.claim_type_beneficiary(&caller, claim_type).clear(),
+            None => self.beneficiary(&caller).clear(),
         }
-        let owner = self.blockchain().get_owner_address();
-        let claim_token = self.claim_token().get();
-        // Send the removed tokens from the claim back to the owner of the smart contract
-        self.send()
-            .direct_esdt(&owner, &claim_token, 0, &sum_of_claims);
+        self.beneficiary_cleared_event(&caller, &claim_type);
     }
 
     // Endpoint available for the public to claim tokens reserved for the calling address. Cannot be called while contract is paused for the public/(harvesting is paused).
     // Can be given an argument as a claim type to harvest only specific claim type. If the claim_type argument is not provided, all claim types for the calling addresses will be harvested.
+    // Can also be given a token to harvest only the claims reserved in that token. If the token argument is not provided, the claims in all the whitelisted tokens are harvested, the ESDT ones being sent through a single multi-transfer and EGLD separately.
+    // The NFTs and SFTs reserved for the calling address are harvested as well, in the same multi-transfer as the ESDT tokens.
+    // Tokens still locked by the vesting schedule of a claim are left in the contract until they unlock. Expired claims cannot be harvested and are skipped when harvesting all claim types.
+    // The tokens of each claim type are sent to the beneficiary set by the calling address for it, if any.
+    // Can also be given an amount, together with a token, to harvest only up to that amount of the token. The claim types are then harvested in the order in which they were registered until the amount is reached, leaving the rest in the claims, and the NFTs and SFTs are not harvested.
     #[endpoint(claim)]
-    fn harvest_claim(&self, claim_type: OptionalValue<ClaimType>) {
+    fn harvest_claim(
+        &self,
+        claim_type: OptionalValue<ClaimType>,
+        token: OptionalValue<EgldOrEsdtTokenIdentifier>,
+        amount: OptionalValue<BigUint>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.harvest_claims(
+            &caller,
+            claim_type.into_option(),
+            token.into_option(),
+            amount.into_option(),
+            None,
+            None,
+        );
+    }
+
+    // Endpoint available for the public to harvest up to the given amount of a token from the claims of all claim types of the calling address, similar to the claim endpoint given an amount.
+    // The claim types are harvested in the order in which they were registered until the amount is reached, leaving the rest in the claims.
+    #[endpoint(claimAmount)]
+    fn harvest_claim_amount(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.harvest_claims(&caller, None, Some(token), Some(amount), None, None);
+    }
+
+    // Endpoint available for the public to harvest the claims in a token reserved for the calling address, similar to the claim endpoint, and stake them on its behalf in the staking contract registered for the token instead of sending them.
+    // The beneficiaries set by the calling address are ignored. If the staking call fails, the harvested tokens are restored to the claims they were taken from.
+    #[endpoint(claimAndStake)]
+    fn harvest_claim_and_stake(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        claim_type: OptionalValue<ClaimType>,
+    ) {
         require!(!self.is_paused().get(), ERR_CONTRACT_PAUSED);
-        self.require_claim_token_is_set();
+        require!(
+            !self.staking_contract(&token).is_empty(),
+            ERR_STAKING_CONTRACT_NOT_SET
+        );
 
         let caller = self.blockchain().get_caller();
-        // Initializes the amount of tokens to be harvested to zero.
-        let mut claim = BigUint::zero();
-        // Checks whether the claim type argument is provided.
-        if let OptionalValue::Some(what_type_to_claim) = claim_type {
-            // Sets claim to the given amount of tokens reserved for the calling address and the given claim type.
-            claim = self.claim(&caller, &what_type_to_claim).get();
-            self.require_value_not_zero(&claim);
-
-            // Resets the reserved tokens for the given claim type of the calling address to zero.
-            self.claim(&caller, &what_type_to_claim)
-                .set(BigUint::zero());
-            self.claim_collected_event(&caller, &what_type_to_claim, &claim);
+        let harvested_claims =
+            self.harvest_claims_to_forward(&caller, claim_type.into_option(), &token);
+        let mut amount = BigUint::zero();
+        for harvested_claim in harvested_claims.iter() {
+            amount += &harvested_claim.amount;
+        }
+
+        self.stake_harvested_claims(&caller, &token, amount, harvested_claims);
+    }
+
+    // Endpoint available for the public to harvest the claims in a token reserved for the calling address, for all claim types, and send them to a whitelisted function of a target contract through an asynchronous call with the given arguments.
+    // The beneficiaries set by the calling address are ignored. As the tokens are sent by the smart contract, the function called must credit the address given in its arguments rather than its caller. If the call fails, the harvested tokens are restored to the claims they were taken from.
+    #[endpoint(claimAndExecute)]
+    fn harvest_claim_and_execute(
+        &self,
+        target: ManagedAddress,
+        function: ManagedBuffer,
+        token: EgldOrEsdtTokenIdentifier,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(!self.is_paused().get(), ERR_CONTRACT_PAUSED);
+        require!(
+            self.execute_targets().contains(&ExecuteTarget {
+                address: target.clone(),
+                function: function.clone(),
+            }),
+            ERR_EXECUTE_TARGET_NOT_WHITELISTED
+        );
+
+        let caller = self.blockchain().get_caller();
+        let harvested_claims = self.harvest_claims_to_forward(&caller, None, &token);
+        let mut amount = BigUint::zero();
+        for harvested_claim in harvested_claims.iter() {
+            amount += &harvested_claim.amount;
+        }
+
+        self.execute_harvested_claims(
+            &caller,
+            target,
+            function,
+            &token,
+            amount,
+            args,
+            harvested_claims,
+        );
+    }
+
+    // Endpoint available for the public to claim tokens reserved for the calling address, similar to the claim endpoint, but sending all of them to the given receiver regardless of the beneficiaries set.
+    #[endpoint(claimTo)]
+    fn harvest_claim_to(
+        &self,
+        receiver: ManagedAddress,
+        claim_type: OptionalValue<ClaimType>,
+        token: OptionalValue<EgldOrEsdtTokenIdentifier>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.harvest_claims(
+            &caller,
+            claim_type.into_option(),
+            token.into_option(),
+            None,
+            Some(receiver),
+            None,
+        );
+    }
+
+    // Endpoint available for relayers to harvest all the claims of an address on its behalf, the tokens being sent to the address itself. Cannot be called while harvesting is paused.
+    // The signature must be the ed25519 signature of the address over the address of the smart contract followed by the top encoded relayed claim (address, nonce, deadline, fee token, fee amount), the nonce being the one returned by the viewRelayedClaimNonce view.
+    // An optional fee, also signed by the address, is deducted from the harvested tokens and sent to the relayer.
+    #[endpoint(claimFor)]
+    fn harvest_claim_for(
+        &self,
+        address: ManagedAddress,
+        deadline: u64,
+        signature: ManagedByteArray<64>,
+        fee: OptionalValue<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>>,
+    ) {
+        require!(
+            deadline >= self.blockchain().get_block_timestamp(),
+            ERR_RELAYED_CLAIM_EXPIRED
+        );
+
+        let (fee_token, fee_amount) = match fee {
+            OptionalValue::Some(fee) => fee.into_tuple(),
+            OptionalValue::None => (EgldOrEsdtTokenIdentifier::egld(), BigUint::zero()),
+        };
+        let nonce = self.relayed_claim_nonce(&address).get();
+        let relayed_claim = RelayedClaim {
+            address: address.clone(),
+            nonce,
+            deadline,
+            fee_token,
+            fee_amount,
+        };
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append(
+            &self
+                .serializer()
+                .top_encode_to_managed_buffer(&relayed_claim),
+        );
+        // The address of an account is its ed25519 public key
+        require!(
+            self.crypto().verify_ed25519(
+                address.as_managed_buffer(),
+                &message,
+                signature.as_managed_buffer()
+            ),
+            ERR_INVALID_SIGNATURE
+        );
+        self.relayed_claim_nonce(&address).set(nonce + 1);
+
+        let caller = self.blockchain().get_caller();
+        self.claim_relayed_event(
+            &address,
+            &caller,
+            nonce,
+            &relayed_claim.fee_token,
+            &relayed_claim.fee_amount,
+        );
+
+        let relayer_fee = if relayed_claim.fee_amount > BigUint::zero() {
+            Some(EgldOrEsdtTokenPayment::new(
+                relayed_claim.fee_token,
+                0,
+                relayed_claim.fee_amount,
+            ))
         } else {
-            // Sets claim to the sum of all reserved tokens for the calling address.
-            for claim_type in 0..ClaimType::len() {
-                let current_claim_type = ClaimType::from(claim_type);
-                let current_claim = self.claim(&caller, &current_claim_type).get();
-                if current_claim > BigUint::zero() {
-                    claim += &current_claim;
-                    self.claim_collected_event(&caller, &current_claim_type, &current_claim);
-                    self.claim(&caller, &current_claim_type)
-                        .set(BigUint::zero());
+            None
+        };
+        self.harvest_claims(
+            &address,
+            None,
+            None,
+            None,
+            Some(address.clone()),
+            relayer_fee,
+        );
+    }
+
+    // Harvests the claims of an address for the given claim type and token, or for all of them, and sends them to the given receiver or else to the beneficiary of each claim type.
+    // If an amount is given, only up to that amount of the token is harvested, from the claim types in the order in which they were registered.
+    // If a relayer fee is given, it is deducted from the harvested tokens and sent to the caller.
+    fn harvest_claims(
+        &self,
+        address: &ManagedAddress,
+        what_type_to_claim: Option<ClaimType>,
+        token: Option<EgldOrEsdtTokenIdentifier>,
+        amount: Option<BigUint>,
+        receiver: Option<ManagedAddress>,
+        relayer_fee: Option<EgldOrEsdtTokenPayment>,
+    ) {
+        require!(!self.is_paused().get(), ERR_CONTRACT_PAUSED);
+
+        // Checks whether the claim type argument is provided.
+        if let Some(what_type_to_claim) = &what_type_to_claim {
+            self.require_claim_type_is_registered(what_type_to_claim);
+            self.require_claim_type_not_paused(what_type_to_claim);
+            self.require_claim_not_expired(address, what_type_to_claim);
+        }
+        if let Some(amount) = &amount {
+            require!(token.is_some(), ERR_HARVEST_AMOUNT_WITHOUT_TOKEN);
+            self.require_value_not_zero(amount);
+        }
+
+        let tokens_to_claim: ManagedVec<EgldOrEsdtTokenIdentifier> = match &token {
+            Some(token) => ManagedVec::from_single_item(token.clone()),
+            None => self.claim_tokens().iter().collect(),
+        };
+
+        // Sets the claim types to harvest with the address to which each of them is sent, and the distinct receivers among them
+        let mut claim_types = ManagedVec::<Self::Api, ClaimType>::new();
+        let mut claim_type_receivers = ManagedVec::<Self::Api, ManagedAddress>::new();
+        let mut receivers = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for claim_type in self.claim_types().keys() {
+            if !self.is_claim_type_harvestable(address, &claim_type, &what_type_to_claim) {
+                continue;
+            }
+
+            let claim_type_receiver = match &receiver {
+                Some(receiver) => receiver.clone(),
+                None => self.view_claim_receiver(address, &claim_type),
+            };
+            if !receivers.contains(&claim_type_receiver) {
+                receivers.push(claim_type_receiver.clone());
+            }
+            claim_types.push(claim_type);
+            claim_type_receivers.push(claim_type_receiver);
+        }
+
+        // Sets the maximum amount to harvest from each claim type, taking the amount given from the claim types in order
+        let mut claim_type_limits = ManagedVec::<Self::Api, BigUint>::new();
+        if let (Some(amount), Some(token)) = (&amount, &token) {
+            let mut remaining = amount.clone();
+            for claim_type in claim_types.iter() {
+                let unlocked = self.get_claim(address, &claim_type, token)
+                    - self.view_locked_claim(address, &claim_type, token);
+                let limit = if unlocked < remaining {
+                    unlocked
+                } else {
+                    remaining.clone()
+                };
+                remaining -= &limit;
+                claim_type_limits.push(limit);
+            }
+        }
+
+        let mut harvested = false;
+        let mut relayer_fee_paid = false;
+        for receiver in receivers.iter() {
+            // Sets the payments to the sum of unlocked tokens reserved for the address for each token, for the claim types sent to the receiver.
+            let mut egld_payment = BigUint::zero();
+            let mut payments = ManagedVec::new();
+            for token in tokens_to_claim.iter() {
+                let mut claim = BigUint::zero();
+                for (index, claim_type) in claim_types.iter().enumerate() {
+                    if *claim_type_receivers.get(index) == *receiver {
+                        let limit = if amount.is_some() {
+                            Some((*claim_type_limits.get(index)).clone())
+                        } else {
+                            None
+                        };
+                        claim += self
+                            .harvest_unlocked_claim(address, &claim_type, &token, limit)
+                            .amount;
+                    }
+                }
+
+                if let Some(relayer_fee) = &relayer_fee {
+                    if relayer_fee.token_identifier == token {
+                        require!(claim >= relayer_fee.amount, ERR_RELAYER_FEE_TOO_HIGH);
+                        claim -= &relayer_fee.amount;
+                        relayer_fee_paid = true;
+                    }
+                }
+
+                if claim == BigUint::zero() {
+                    continue;
+                }
+                if token.is_egld() {
+                    egld_payment = claim;
+                } else {
+                    payments.push(EsdtTokenPayment::new(token.unwrap_esdt(), 0, claim));
                 }
             }
-            self.require_value_not_zero(&claim);
+
+            // Adds the NFTs and SFTs reserved for the address to the ESDT payments, unless only an amount of a token is harvested
+            for (index, claim_type) in claim_types.iter().enumerate() {
+                if amount.is_none() && *claim_type_receivers.get(index) == *receiver {
+                    self.harvest_nft_claims(address, &claim_type, &token, &mut payments);
+                }
+            }
+
+            if egld_payment == BigUint::zero() && payments.is_empty() && !relayer_fee_paid {
+                continue;
+            }
+            harvested = true;
+            if *receiver != *address {
+                self.claim_redirected_event(address, &receiver);
+            }
+
+            // Send the tokens harvested (all tokens of a given claim type or the sum for all claim types) to the receiver.
+            if egld_payment > BigUint::zero() {
+                self.send().direct_egld(&receiver, &egld_payment);
+            }
+            if !payments.is_empty() {
+                self.send().direct_multi(&receiver, &payments);
+            }
+        }
+        require!(harvested, ERR_NON_ZERO_VALUE);
+
+        if let Some(relayer_fee) = relayer_fee {
+            require!(relayer_fee_paid, ERR_RELAYER_FEE_TOO_HIGH);
+            let caller = self.blockchain().get_caller();
+            self.send().direct(
+                &caller,
+                &relayer_fee.token_identifier,
+                0,
+                &relayer_fee.amount,
+            );
+        }
+    }
+
+    // Harvests the unlocked claims of an address in a token, for the given claim type or for all of them, in order to forward them to another contract, and returns the amount harvested from each claim type. Panics if nothing is harvested
+    fn harvest_claims_to_forward(
+        &self,
+        address: &ManagedAddress,
+        what_type_to_claim: Option<ClaimType>,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedVec<HarvestedClaim<Self::Api>> {
+        if let Some(what_type_to_claim) = &what_type_to_claim {
+            self.require_claim_type_is_registered(what_type_to_claim);
+            self.require_claim_type_not_paused(what_type_to_claim);
+            self.require_claim_not_expired(address, what_type_to_claim);
+        }
+
+        let mut harvested_claims = ManagedVec::new();
+        for claim_type in self.claim_types().keys() {
+            if !self.is_claim_type_harvestable(address, &claim_type, &what_type_to_claim) {
+                continue;
+            }
+
+            let harvested_claim = self.harvest_unlocked_claim(address, &claim_type, token, None);
+            if harvested_claim.amount > 0 {
+                harvested_claims.push(harvested_claim);
+            }
+        }
+        require!(!harvested_claims.is_empty(), ERR_NON_ZERO_VALUE);
+
+        harvested_claims
+    }
+
+    // Checks whether a claim type is harvested by a call to the claim endpoint, given the claim type argument of the call. Expired claims and paused claim types are never harvested
+    fn is_claim_type_harvestable(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        what_type_to_claim: &Option<ClaimType>,
+    ) -> bool {
+        if let Some(what_type_to_claim) = what_type_to_claim {
+            if what_type_to_claim != claim_type {
+                return false;
+            }
+        }
+
+        !self.paused_claim_types().contains(claim_type)
+            && !self.is_claim_expired(address, claim_type)
+    }
+
+    // Harvests the unlocked tokens of a claim, up to the given limit if any, leaving the locked tokens in it, and returns the amount harvested with the shares taken from the funding ledger and the campaigns of the claim. The vesting schedule is cleared once it is fully unlocked.
+    fn harvest_unlocked_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        limit: Option<BigUint>,
+    ) -> HarvestedClaim<Self::Api> {
+        let locked = self.view_locked_claim(address, claim_type, token);
+        let current_claim = self.get_claim(address, claim_type, token);
+        let mut claim = &current_claim - &locked;
+        if let Some(limit) = limit {
+            if limit < claim {
+                claim = limit;
+            }
+        }
+        if claim == BigUint::zero() {
+            return HarvestedClaim {
+                claim_type: *claim_type,
+                amount: claim,
+                fundings: ManagedVec::new(),
+                campaigns: ManagedVec::new(),
+            };
+        }
+
+        // The harvested tokens are no longer refundable to the depositors that funded them, and the claim can no longer be clawed back.
+        // Only the shares taken from the ledger are kept, without the share attributed to the admin for the part of the claim not in the ledger
+        self.clear_claim_deposits(address, claim_type, token);
+        let ledger_len = self.claim_funding(address, claim_type, token).len();
+        let fundings = self.take_claim_funding(address, claim_type, token, &current_claim, &claim);
+        let fundings = fundings.slice(0, ledger_len).unwrap_or(fundings);
+        let campaigns = self.take_claim_campaigns(
+            address,
+            claim_type,
+            token,
+            &current_claim,
+            &claim,
+            ClaimMovementKind::Harvested,
+        );
+
+        if locked == BigUint::zero() {
+            self.vesting_schedule(address, claim_type, token).clear();
+        }
+        self.set_claim(address, claim_type, token, &current_claim - &claim);
+        self.decrease_reserved(Some(claim_type), token, &claim);
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.claim_modify_date(address, claim_type).set(timestamp);
+        self.record_claim_movement(
+            address,
+            claim_type,
+            token,
+            &claim,
+            ClaimMovementKind::Harvested,
+        );
+        self.claim_collected_event(address, claim_type, token, &claim);
+
+        HarvestedClaim {
+            claim_type: *claim_type,
+            amount: claim,
+            fundings,
+            campaigns,
         }
-        // Send the amount of tokens harvested (all tokens of a given claim type or the sum for all claim types) to the calling address.
-        let claim_token = self.claim_token().get();
-        self.send().direct_esdt(&caller, &claim_token, 0, &claim);
+    }
+
+    // Sets the expiry of a claim, recording the depositor that set it. An expiry can only be set on a claim funded by the depositor alone and is never shortened, so that it only applies to the deposits of that depositor
+    fn set_claim_expiry(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        expiry: u64,
+        depositor: &ManagedAddress,
+    ) {
+        let claim_expiry = self.claim_expiry(address, claim_type);
+        if !claim_expiry.is_empty() {
+            let current_expiry = claim_expiry.get();
+            require!(
+                &current_expiry.depositor == depositor,
+                ERR_CLAIM_EXPIRY_SET_BY_OTHER_DEPOSITOR
+            );
+            if current_expiry.timestamp >= expiry {
+                return;
+            }
+        } else {
+            require!(
+                self.is_claim_funded_only_by(address, claim_type, depositor),
+                ERR_CLAIM_FUNDED_BY_OTHER_DEPOSITORS
+            );
+        }
+
+        claim_expiry.set(ClaimExpiry {
+            timestamp: expiry,
+            depositor: depositor.clone(),
+        });
     }
 }
@@ -0,0 +1,254 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    admin, campaigns,
+    constants::*,
+    depositors, events, requirements,
+    storage::{self, AdminOperation, ClaimMovementKind, ClaimType, NftNonce, Role},
+    views,
+};
+
+// Module that handles claims of NFTs and SFTs, which reserve specific nonces (and SFT quantities) of a collection instead of a fungible amount
+#[multiversx_sc::module]
+pub trait NftsModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + admin::AdminModule
+    + campaigns::CampaignsModule
+    + depositors::DepositorsModule
+{
+    // Endpoint available for privileged addresses and depositors of the smart contract to reserve the NFTs and SFTs sent for a specific address and claim type.
+    // Multiple nonces can be sent in the same transaction, all of them must belong to whitelisted collections.
+    #[payable("*")]
+    #[endpoint(addNftClaim)]
+    fn add_nft_claim(&self, address: &ManagedAddress, claim_type: ClaimType) {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), ERR_NON_ZERO_VALUE);
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_deposit_rights(&caller);
+        self.require_claim_type_is_enabled(&claim_type);
+        self.require_depositor_can_add_claim_type(&caller, &claim_type);
+        self.require_claim_not_expired(address, &claim_type);
+        self.require_claim_expiry_allows_depositor(address, &claim_type, &caller);
+
+        for payment in payments.iter() {
+            require!(payment.token_nonce > 0, ERR_TOKEN_NOT_NFT);
+            self.require_token_is_whitelisted(&EgldOrEsdtTokenIdentifier::esdt(
+                payment.token_identifier.clone(),
+            ));
+
+            let nft_nonce = NftNonce {
+                token: payment.token_identifier.clone(),
+                nonce: payment.token_nonce,
+            };
+            let current_claim = self
+                .nft_claims(address, &claim_type)
+                .get(&nft_nonce)
+                .unwrap_or_default();
+            self.increase_nft_reserved(&nft_nonce, &payment.amount);
+            let mut nft_claim_funding = self.nft_claim_funding(address, &claim_type, &nft_nonce);
+            let funded = nft_claim_funding.get(&caller).unwrap_or_default();
+            nft_claim_funding.insert(caller.clone(), funded + &payment.amount);
+            self.set_nft_claim(
+                address,
+                &claim_type,
+                &nft_nonce,
+                current_claim + &payment.amount,
+            );
+            self.record_nft_claim_movement(
+                address,
+                &claim_type,
+                &nft_nonce,
+                &payment.amount,
+                ClaimMovementKind::Added,
+            );
+            self.nft_claim_added_event(
+                &caller,
+                address,
+                &claim_type,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
+        }
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.claim_modify_date(address, &claim_type).set(timestamp);
+    }
+
+    // Endpoint available for the admin of the smart contract and the addresses with the ClaimRemover role to remove a quantity of an NFT or SFT nonce from a claim. The removed tokens are refunded to the depositors that added them, proportionally to the quantity each of them added.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(removeNftClaim)]
+    fn remove_nft_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: ClaimType,
+        token: TokenIdentifier,
+        nonce: u64,
+        amount: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_role(&Role::ClaimRemover, &caller);
+        self.require_value_not_zero(&amount);
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::RemoveNftClaim {
+                address: address.clone(),
+                claim_type,
+                token,
+                nonce,
+                amount,
+            });
+            return;
+        }
+        self.execute_remove_nft_claim(address, claim_type, token, nonce, amount);
+    }
+
+    // Removes a quantity of an NFT or SFT nonce from a claim and refunds it to the depositors that added it, once the admin operation is executed
+    fn execute_remove_nft_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: ClaimType,
+        token: TokenIdentifier,
+        nonce: u64,
+        amount: BigUint,
+    ) {
+        let nft_nonce = NftNonce {
+            token: token.clone(),
+            nonce,
+        };
+        let current_claim = self
+            .nft_claims(address, &claim_type)
+            .get(&nft_nonce)
+            .unwrap_or_default();
+        self.require_remove_claim_is_valid(&current_claim, &amount);
+
+        let refunds = self.take_funding(
+            self.nft_claim_funding(address, &claim_type, &nft_nonce),
+            &current_claim,
+            &amount,
+        );
+        self.decrease_nft_reserved(&nft_nonce, &amount);
+        self.set_nft_claim(address, &claim_type, &nft_nonce, current_claim - &amount);
+        self.record_nft_claim_movement(
+            address,
+            &claim_type,
+            &nft_nonce,
+            &amount,
+            ClaimMovementKind::Removed,
+        );
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.claim_modify_date(address, &claim_type).set(timestamp);
+
+        for refund in refunds.iter() {
+            if refund.amount == BigUint::zero() {
+                continue;
+            }
+            self.nft_claim_removed_event(
+                address,
+                &claim_type,
+                &token,
+                nonce,
+                &refund.amount,
+                &refund.depositor,
+            );
+            self.send()
+                .direct_esdt(&refund.depositor, &token, nonce, &refund.amount);
+        }
+    }
+
+    // Removes the NFTs and SFTs reserved for an address and claim type, optionally only the ones of a given collection, and adds them to the payments to be sent
+    fn harvest_nft_claims(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &Option<EgldOrEsdtTokenIdentifier>,
+        payments: &mut ManagedVec<EsdtTokenPayment>,
+    ) {
+        let mut harvested = ManagedVec::<Self::Api, NftNonce<Self::Api>>::new();
+        for (nft_nonce, amount) in self.nft_claims(address, claim_type).iter() {
+            if let Some(token) = token {
+                if token != &EgldOrEsdtTokenIdentifier::esdt(nft_nonce.token.clone()) {
+                    continue;
+                }
+            }
+
+            self.nft_claim_collected_event(
+                address,
+                claim_type,
+                &nft_nonce.token,
+                nft_nonce.nonce,
+                &amount,
+            );
+            self.decrease_nft_reserved(&nft_nonce, &amount);
+            self.record_nft_claim_movement(
+                address,
+                claim_type,
+                &nft_nonce,
+                &amount,
+                ClaimMovementKind::Harvested,
+            );
+            payments.push(EsdtTokenPayment::new(
+                nft_nonce.token.clone(),
+                nft_nonce.nonce,
+                amount,
+            ));
+            harvested.push(nft_nonce);
+        }
+
+        for nft_nonce in harvested.iter() {
+            self.nft_claim_funding(address, claim_type, &nft_nonce)
+                .clear();
+            self.set_nft_claim(address, claim_type, &nft_nonce, BigUint::zero());
+        }
+    }
+
+    // Removes all the NFTs and SFTs reserved for an expired claim and refunds them to the depositors that added them
+    fn reclaim_expired_nft_claims(&self, address: &ManagedAddress, claim_type: &ClaimType) {
+        let mut reclaimed = ManagedVec::<Self::Api, NftNonce<Self::Api>>::new();
+        for (nft_nonce, amount) in self.nft_claims(address, claim_type).iter() {
+            let refunds = self.take_funding(
+                self.nft_claim_funding(address, claim_type, &nft_nonce),
+                &amount,
+                &amount,
+            );
+            self.decrease_nft_reserved(&nft_nonce, &amount);
+            self.record_nft_claim_movement(
+                address,
+                claim_type,
+                &nft_nonce,
+                &amount,
+                ClaimMovementKind::Removed,
+            );
+            for refund in refunds.iter() {
+                if refund.amount == BigUint::zero() {
+                    continue;
+                }
+                self.nft_claim_expired_event(
+                    address,
+                    claim_type,
+                    &refund.depositor,
+                    &nft_nonce.token,
+                    nft_nonce.nonce,
+                    &refund.amount,
+                );
+                self.send().direct_esdt(
+                    &refund.depositor,
+                    &nft_nonce.token,
+                    nft_nonce.nonce,
+                    &refund.amount,
+                );
+            }
+            reclaimed.push(nft_nonce);
+        }
+
+        for nft_nonce in reclaimed.iter() {
+            self.set_nft_claim(address, claim_type, &nft_nonce, BigUint::zero());
+        }
+    }
+}
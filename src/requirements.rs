@@ -1,19 +1,60 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::{constants::*, storage};
+use crate::{
+    constants::*,
+    storage,
+    storage::{ClaimType, Role},
+    views,
+};
 
 // Module that handles generic (commonly used, which are not specific to one function) requirements which should stop execution and rollback if not met
 #[multiversx_sc::module]
-pub trait RequirementsModule: storage::StorageModule {
-    // Checks whether the owner of the smart contract designated a token to be used by the smart contract for all the claims
-    fn require_claim_token_is_set(&self) {
-        require!(!self.claim_token().is_empty(), ERR_TOKEN_NOT_SET);
+pub trait RequirementsModule: storage::StorageModule + views::ViewsModule {
+    // Checks whether a given token identifier is in the whitelist of tokens that can be used for claims
+    fn require_token_is_whitelisted(&self, token: &EgldOrEsdtTokenIdentifier) {
+        require!(
+            self.claim_tokens().contains(token),
+            ERR_TOKEN_NOT_WHITELISTED
+        );
+    }
+
+    // Checks whether a claim type is registered, unknown claim types being rejected instead of falling back to another claim type
+    fn require_claim_type_is_registered(&self, claim_type: &ClaimType) {
+        require!(
+            self.claim_types().contains_key(claim_type),
+            ERR_CLAIM_TYPE_NOT_REGISTERED
+        );
+    }
+
+    // Checks whether a claim type is registered and enabled, so that new claims can be added for it
+    fn require_claim_type_is_enabled(&self, claim_type: &ClaimType) {
+        self.require_claim_type_is_registered(claim_type);
+        require!(
+            self.claim_types().get(claim_type).unwrap().enabled,
+            ERR_CLAIM_TYPE_NOT_ENABLED
+        );
+    }
+
+    // Checks whether a depositor can add claims of a claim type, the depositors without claim types being allowed to add claims of any claim type
+    fn require_depositor_can_add_claim_type(
+        &self,
+        depositor: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) {
+        let depositor_claim_types = self.depositor_claim_types(depositor);
+        require!(
+            depositor_claim_types.is_empty() || depositor_claim_types.contains(claim_type),
+            ERR_DEPOSITOR_CLAIM_TYPE_NOT_ALLOWED
+        );
     }
 
-    // Checks whether a given token identifier is equal to the token identifier of the token used by the smart contract claims
-    fn require_token_is_correct(&self, token: TokenIdentifier) {
-        require!(token == self.claim_token().get(), ERR_TOKEN_INCORRECT);
+    // Checks whether the harvesting of a claim type is not paused
+    fn require_claim_type_not_paused(&self, claim_type: &ClaimType) {
+        require!(
+            !self.paused_claim_types().contains(claim_type),
+            ERR_CLAIM_TYPE_PAUSED
+        );
     }
 
     // Checks whether a value is not zero
@@ -26,6 +67,46 @@ pub trait RequirementsModule: storage::StorageModule {
         require!(current_claim >= amount, ERR_MORE_THAN_CLAIM);
     }
 
+    // Checks whether a vesting schedule starts before it ends and has its cliff between the start and the end
+    fn require_vesting_schedule_is_valid(&self, start: u64, cliff: u64, end: u64) {
+        require!(
+            start < end && start <= cliff && cliff <= end,
+            ERR_INVALID_VESTING_SCHEDULE
+        );
+    }
+
+    // Checks whether an expiry timestamp given for a claim is in the future, within the maximum expiry period
+    fn require_expiry_is_valid(&self, expiry: u64) {
+        let timestamp = self.blockchain().get_block_timestamp();
+        require!(expiry > timestamp, ERR_INVALID_EXPIRY);
+        require!(
+            expiry - timestamp <= MAX_CLAIM_EXPIRY_PERIOD,
+            ERR_EXPIRY_TOO_FAR
+        );
+    }
+
+    // Checks whether the claim of an address and claim type has not expired
+    fn require_claim_not_expired(&self, address: &ManagedAddress, claim_type: &ClaimType) {
+        require!(
+            !self.is_claim_expired(address, claim_type),
+            ERR_CLAIM_EXPIRED
+        );
+    }
+
+    // Checks whether a depositor can add to the claim of an address and claim type, which is not the case while the claim has an expiry set by another depositor, as an expiry only applies to the deposits of the depositor that set it
+    fn require_claim_expiry_allows_depositor(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        depositor: &ManagedAddress,
+    ) {
+        let claim_expiry = self.claim_expiry(address, claim_type);
+        require!(
+            claim_expiry.is_empty() || &claim_expiry.get().depositor == depositor,
+            ERR_CLAIM_EXPIRY_SET_BY_OTHER_DEPOSITOR
+        );
+    }
+
     // Checks whether the number of claims added or removed is smaller than 200. Implemented in order to ensure no call will fail due to consuming more than the maximum gas allowed per transaction on Elrond.
     fn require_number_of_claims_in_bulk_is_valid(&self, number_of_claims: &usize) {
         require!(
@@ -34,22 +115,32 @@ pub trait RequirementsModule: storage::StorageModule {
         );
     }
 
-    // Checks whether the address has the special rights needed in case of some special operations
-    fn require_address_is_privileged(&self, address: &ManagedAddress) {
+    // Checks whether the address is the admin of the smart contract
+    fn require_address_is_admin(&self, address: &ManagedAddress) {
+        require!(&self.admin().get() == address, ERR_ADDRESS_NOT_ADMIN);
+    }
+
+    // Checks whether the address is the admin of the smart contract or has been granted the given role
+    fn require_address_has_role(&self, role: &Role, address: &ManagedAddress) {
+        require!(self.has_role(role, address), ERR_ADDRESS_NOT_AUTHORIZED);
+    }
+
+    // Checks whether the address can grant and revoke the given role. The admin of the smart contract can manage all roles, while the addresses with the Admin role can manage all the other roles
+    fn require_address_can_manage_role(&self, address: &ManagedAddress, role: &Role) {
         require!(
-            self.privileged_addresses().contains(address)
-                || &self.blockchain().get_owner_address() == address,
+            &self.admin().get() == address
+                || (role != &Role::Admin && self.role_members(&Role::Admin).contains(address)),
             ERR_ADDRESS_NOT_AUTHORIZED
         );
     }
 
-    // Checks whether the address has the Data NFT Marketplace Special Rights
+    // Checks whether the address has the special rights needed in case of some special operations, which are given by the Pauser role
+    fn require_address_is_privileged(&self, address: &ManagedAddress) {
+        self.require_address_has_role(&Role::Pauser, address);
+    }
+
+    // Checks whether the address has the Data NFT Marketplace Special Rights, which are given by the Depositor role
     fn require_address_has_deposit_rights(&self, address: &ManagedAddress) {
-        require!(
-            self.privileged_addresses().contains(address)
-                || &self.blockchain().get_owner_address() == address
-                || self.depositor_addresses().contains(&address),
-            ERR_ADDRESS_NOT_AUTHORIZED
-        );
+        self.require_address_has_role(&Role::Depositor, address);
     }
 }
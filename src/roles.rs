@@ -0,0 +1,66 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    admin,
+    constants::*,
+    events, requirements,
+    storage::{self, AdminOperation, Role},
+    views,
+};
+
+// Module that handles the roles of the smart contract, which allow addresses to pause, unpause, deposit or remove claims, or to manage the other roles
+#[multiversx_sc::module]
+pub trait RolesModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + admin::AdminModule
+{
+    // Endpoint available for the admin of the smart contract and the addresses with the Admin role to grant a role to an address. Only the admin of the smart contract can grant the Admin role.
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_can_manage_role(&caller, &role);
+        require!(
+            self.role_members(&role).insert(address.clone()),
+            ERR_ROLE_ALREADY_GRANTED
+        );
+        self.role_granted_event(&role, &address, &caller);
+    }
+
+    // Endpoint available for the admin of the smart contract and the addresses with the Admin role to revoke a role from an address. Only the admin of the smart contract can revoke the Admin role.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_can_manage_role(&caller, &role);
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::RevokeRole { role, address });
+            return;
+        }
+        self.execute_revoke_role(role, address);
+    }
+
+    // Endpoint available for any address to renounce one of the roles it has been granted.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role).swap_remove(&caller),
+            ERR_ROLE_NOT_GRANTED
+        );
+        self.role_renounced_event(&role, &caller);
+    }
+
+    // Revokes a role from an address, once the admin operation is executed
+    fn execute_revoke_role(&self, role: Role, address: ManagedAddress) {
+        require!(
+            self.role_members(&role).swap_remove(&address),
+            ERR_ROLE_NOT_GRANTED
+        );
+        self.role_revoked_event(&role, &address);
+    }
+}
@@ -0,0 +1,126 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    admin, campaigns,
+    constants::*,
+    depositors, events, requirements,
+    storage::{self, AdminOperation, HarvestedClaim, StakingContract},
+    views,
+};
+
+// Module that handles the staking contracts to which harvested claims can be forwarded on behalf of their addresses
+#[multiversx_sc::module]
+pub trait StakingModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + admin::AdminModule
+    + campaigns::CampaignsModule
+    + depositors::DepositorsModule
+{
+    // Endpoint available for the admin of the smart contract to register the staking contract to which the claims in a token are forwarded by the claimAndStake endpoint.
+    // The endpoint of the staking contract is called with the harvested tokens and the address on behalf of which they are staked as its only argument. Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(setStakingContract)]
+    fn set_staking_contract(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        address: ManagedAddress,
+        endpoint: ManagedBuffer,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.require_token_is_whitelisted(&token);
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            ERR_NOT_A_SMART_CONTRACT
+        );
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::SetStakingContract {
+                token,
+                address,
+                endpoint,
+            });
+            return;
+        }
+        self.execute_set_staking_contract(token, address, endpoint);
+    }
+
+    // Registers the staking contract of a token, once the admin operation is executed
+    fn execute_set_staking_contract(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        address: ManagedAddress,
+        endpoint: ManagedBuffer,
+    ) {
+        self.staking_contract_set_event(&token, &address, &endpoint);
+        self.staking_contract(&token)
+            .set(StakingContract { address, endpoint });
+    }
+
+    // Endpoint available for the admin of the smart contract to remove the staking contract of a token, after which its claims can no longer be staked.
+    #[endpoint(clearStakingContract)]
+    fn clear_staking_contract(&self, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        require!(
+            !self.staking_contract(&token).is_empty(),
+            ERR_STAKING_CONTRACT_NOT_SET
+        );
+
+        self.staking_contract(&token).clear();
+        self.staking_contract_cleared_event(&token);
+    }
+
+    // Forwards the tokens harvested from the claims of an address to the staking contract of the token on its behalf. The claims are restored by the callback if the staking call fails
+    fn stake_harvested_claims(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        harvested_claims: ManagedVec<HarvestedClaim<Self::Api>>,
+    ) -> ! {
+        let staking_contract = self.staking_contract(token).get();
+        let mut contract_call = self
+            .send()
+            .contract_call::<()>(staking_contract.address.clone(), staking_contract.endpoint)
+            .with_egld_or_single_esdt_transfer((token.clone(), 0, amount.clone()));
+        contract_call.push_raw_argument(address.as_managed_buffer().clone());
+
+        contract_call
+            .async_call()
+            .with_callback(self.callbacks().stake_callback(
+                address.clone(),
+                token.clone(),
+                amount,
+                staking_contract.address,
+                harvested_claims,
+            ))
+            .call_and_exit()
+    }
+
+    // Callback of the staking call, which restores the harvested tokens to the claims they were taken from if the staking call failed,
+    // attributing them again to the depositors that funded them and to the campaigns for which they were added
+    #[callback]
+    fn stake_callback(
+        &self,
+        address: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        staking_contract: ManagedAddress,
+        harvested_claims: ManagedVec<HarvestedClaim<Self::Api>>,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.claim_staked_event(&address, &token, &amount, &staking_contract);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.restore_harvested_claims(&address, &token, &harvested_claims);
+                self.claim_stake_failed_event(&address, &token, &amount);
+            }
+        }
+    }
+}
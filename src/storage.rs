@@ -1,53 +1,417 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-// Enumeration used to define claim types and increase readability of the code
+use crate::constants::*;
+
+// Identifier of a claim type. Claim types are registered by the admin of the smart contract, the default ones being defined in the constants file
+pub type ClaimType = u8;
+
+// Structure that holds the name of a registered claim type and whether new claims can be added for it
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
-pub enum ClaimType {
-    Reward,
-    Airdrop,
-    Allocation,
-    Royalty,
+pub struct ClaimTypeInfo<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub enabled: bool,
 }
 
-// Trait used to define the maximum value of the ClaimType enumeration
-pub trait Len {
-    fn len() -> u8;
+// Structure that defines the linear vesting schedule of a claim. Nothing is unlocked before the cliff, after which tokens unlock linearly between start and end
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct VestingSchedule<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
 }
 
-// Implementation of the Max trait for the ClaimType enumeration
-impl Len for ClaimType {
-    fn len() -> u8 {
-        4
+impl<M: ManagedTypeApi> VestingSchedule<M> {
+    // Returns the amount of tokens of the schedule that are still locked at the given timestamp
+    pub fn locked_amount(&self, timestamp: u64) -> BigUint<M> {
+        if timestamp < self.cliff || timestamp <= self.start {
+            return self.amount.clone();
+        }
+        if timestamp >= self.end {
+            return BigUint::zero();
+        }
+
+        let vested = &self.amount * (timestamp - self.start) / (self.end - self.start);
+        &self.amount - &vested
     }
 }
 
-// Implementation of the From trait for the ClaimType enumeration
-impl From<u8> for ClaimType {
-    fn from(claim_type: u8) -> Self {
-        match claim_type {
-            0 => ClaimType::Reward,
-            1 => ClaimType::Airdrop,
-            2 => ClaimType::Allocation,
-            3 => ClaimType::Royalty,
-            _ => ClaimType::Reward,
+// Structure that holds the timestamp after which a claim expires and the depositor that set it, which is the only depositor that can add to the claim while it has an expiry
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct ClaimExpiry<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub depositor: ManagedAddress<M>,
+}
+
+// Structure that holds a distribution, which reserves the funded amount for the addresses in the Merkle tree with the given root until its deadline, zero meaning no deadline
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct Distribution<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub root: ManagedByteArray<M, 32>,
+    pub depositor: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub deadline: u64,
+}
+
+// Structure that identifies a specific nonce of an NFT or SFT collection reserved in a claim
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Debug,
+    TypeAbi,
+)]
+pub struct NftNonce<M: ManagedTypeApi> {
+    pub token: TokenIdentifier<M>,
+    pub nonce: u64,
+}
+
+// Structure that defines the content of the authorization signed by the owner of a claim for harvesting it through a relayer
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct RelayedClaim<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub nonce: u64,
+    pub deadline: u64,
+    pub fee_token: EgldOrEsdtTokenIdentifier<M>,
+    pub fee_amount: BigUint<M>,
+}
+
+// Enum that defines the roles which can be granted to addresses in order to allow them specific operations. The admin of the smart contract implicitly has all of them
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug, TypeAbi,
+)]
+pub enum Role {
+    Pauser,
+    Depositor,
+    ClaimRemover,
+    Unpauser,
+    Admin,
+}
+
+// Structure that defines the amount of a claim funded by a depositor
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Debug,
+    TypeAbi,
+)]
+pub struct ClaimFunding<M: ManagedTypeApi> {
+    pub depositor: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+}
+
+// Splits an amount, which must not exceed the total of the parts, between the parts proportionally to their amounts. The rounding remainder is taken from the first parts that have enough left
+pub fn split_proportionally<M: ManagedTypeApi>(
+    parts: &ManagedVec<M, BigUint<M>>,
+    amount: &BigUint<M>,
+) -> ManagedVec<M, BigUint<M>> {
+    let mut total = BigUint::zero();
+    for part in parts.iter() {
+        total += &*part;
+    }
+
+    let mut shares = ManagedVec::new();
+    let mut remainder = amount.clone();
+    for part in parts.iter() {
+        let share = if total == BigUint::zero() {
+            BigUint::zero()
+        } else {
+            &*part * amount / &total
+        };
+        remainder -= &share;
+        shares.push(share);
+    }
+    for index in 0..parts.len() {
+        if remainder == BigUint::zero() {
+            break;
         }
+        let mut share = (*shares.get(index)).clone();
+        let left = &*parts.get(index) - &share;
+        let extra = if left < remainder {
+            left
+        } else {
+            remainder.clone()
+        };
+        remainder -= &extra;
+        share += extra;
+        let _ = shares.set(index, &share);
     }
+
+    shares
+}
+
+// Structure that defines the maximum amount of a token that a depositor can add to claims, either in total or in each epoch
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct DepositorQuota<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub per_epoch: bool,
+}
+
+// Structure that defines the staking contract to which the harvested tokens of a token are forwarded, and the endpoint called on it
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct StakingContract<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub endpoint: ManagedBuffer<M>,
+}
+
+// Structure that defines a function of a target contract to which the harvested claims can be sent through the claimAndExecute endpoint
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct ExecuteTarget<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub function: ManagedBuffer<M>,
+}
+
+// Structure that defines the amount of a claim taken out of a campaign when the claim is harvested or removed
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Debug,
+    TypeAbi,
+)]
+pub struct CampaignShare<M: ManagedTypeApi> {
+    pub campaign_id: u64,
+    pub amount: BigUint<M>,
+}
+
+// Structure that defines the amount harvested from a claim type in order to be forwarded to another contract, with the shares taken from the funding ledger and the campaigns of the claim, which are restored if the forwarding fails
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Debug,
+    TypeAbi,
+)]
+pub struct HarvestedClaim<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
+    pub amount: BigUint<M>,
+    pub fundings: ManagedVec<M, ClaimFunding<M>>,
+    pub campaigns: ManagedVec<M, CampaignShare<M>>,
+}
+
+// Structure that holds a campaign, which labels the claims added for it, with the totals of its budget deposited, harvested and removed
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct Campaign<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub claim_type: ClaimType,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub budget: BigUint<M>,
+    pub deposited: BigUint<M>,
+    pub harvested: BigUint<M>,
+    pub removed: BigUint<M>,
+}
+
+// Structure that defines an amount added to a claim by a depositor that can be clawed back, and the timestamp of the deposit
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Debug,
+    TypeAbi,
+)]
+pub struct ClaimDeposit<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub timestamp: u64,
+}
+
+// Structure that defines the amount of a token that a depositor has added to claims against its quota, and the epoch of its last deposit
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct DepositorQuotaUsage<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub epoch: u64,
+}
+
+// Structure that identifies a non-zero claim of an address in the claimant registry by its claim type, token and nonce, which is zero for the fungible claims
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct ClaimKey<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub nonce: u64,
+}
+
+// Enum that defines the kinds of movements recorded in the claim history of an address
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug, TypeAbi,
+)]
+pub enum ClaimMovementKind {
+    Added,
+    Removed,
+    Harvested,
+}
+
+// Structure that defines a movement of the claims of an address recorded in its claim history, the amount being the amount added, removed or harvested and the nonce being zero for the fungible claims
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct ClaimHistoryEntry<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+    pub operator: ManagedAddress<M>,
+    pub timestamp: u64,
+    pub kind: ClaimMovementKind,
+}
+
+// Structure that defines a claim of a bulk removal queued in the timelock
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    Clone,
+    Debug,
+    TypeAbi,
+)]
+pub struct ClaimRemoval<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub claim_type: ClaimType,
+    pub amount: BigUint<M>,
+}
+
+// Enum that defines the admin operations which are queued in the timelock whenever a timelock delay is set
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub enum AdminOperation<M: ManagedTypeApi> {
+    RemoveClaim {
+        address: ManagedAddress<M>,
+        claim_type: ClaimType,
+        token: EgldOrEsdtTokenIdentifier<M>,
+        amount: BigUint<M>,
+    },
+    RemoveClaims {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        claims: ManagedVec<M, ClaimRemoval<M>>,
+    },
+    RevokeRole {
+        role: Role,
+        address: ManagedAddress<M>,
+    },
+    AddClaimToken {
+        token: EgldOrEsdtTokenIdentifier<M>,
+    },
+    RemoveClaimToken {
+        token: EgldOrEsdtTokenIdentifier<M>,
+    },
+    SetTimelockDelay {
+        delay: u64,
+    },
+    RemoveNftClaim {
+        address: ManagedAddress<M>,
+        claim_type: ClaimType,
+        token: TokenIdentifier<M>,
+        nonce: u64,
+        amount: BigUint<M>,
+    },
+    WithdrawVoucherPool {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        amount: BigUint<M>,
+    },
+    SetVoucherSigner {
+        signer: ManagedByteArray<M, 32>,
+    },
+    SetStakingContract {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        address: ManagedAddress<M>,
+        endpoint: ManagedBuffer<M>,
+    },
+    AddExecuteTarget {
+        address: ManagedAddress<M>,
+        function: ManagedBuffer<M>,
+    },
+}
+
+// Structure that defines an admin operation queued in the timelock and the timestamp from which it can be executed
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct QueuedOperation<M: ManagedTypeApi> {
+    pub operation: AdminOperation<M>,
+    pub executable_from: u64,
 }
 
 // Module that handles the common storage of the smart contract
 #[multiversx_sc::module]
 pub trait StorageModule {
-    // Stores the token identifier of the token that is used for claims in the smart contract
+    // Stores the claim types registered by the admin of the smart contract with their name and whether they are enabled
+    #[view(viewClaimTypes)]
+    #[storage_mapper("claimTypes")]
+    fn claim_types(&self) -> MapMapper<ClaimType, ClaimTypeInfo<Self::Api>>;
+
+    // Stores the whitelist of token identifiers that can be used for claims in the smart contract
+    #[view(viewClaimTokens)]
+    #[storage_mapper("claimTokens")]
+    fn claim_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    // Stores the token that was used for claims before several tokens could be whitelisted
     #[view(viewTokenIdentifier)]
     #[storage_mapper("tokenIdentifier")]
     fn claim_token(&self) -> SingleValueMapper<TokenIdentifier>;
 
-    // Stores the amount available to claim for each address and claim type
-    #[view(viewClaim)]
+    // Stores whether the token that was used for claims before several tokens could be whitelisted has been added to the whitelist
+    #[storage_mapper("claimTokenMigrated")]
+    fn claim_token_migrated(&self) -> SingleValueMapper<bool>;
+
+    // Stores the amount available to claim for each address, claim type and token. Claims are read through get_claim, which includes the legacy claims
+    #[storage_mapper("claim")]
+    fn claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the amount available to claim for each address and claim type in the legacy claim token, as stored before several tokens could be whitelisted. Legacy claims are moved to the claim storage once modified
     #[storage_mapper("claim")]
-    fn claim(&self, address: &ManagedAddress, claim_type: &ClaimType)
-        -> SingleValueMapper<BigUint>;
+    fn legacy_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) -> SingleValueMapper<BigUint>;
+
+    // Stores the quantity of each NFT or SFT nonce reserved for each address and claim type
+    #[storage_mapper("nftClaims")]
+    fn nft_claims(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) -> MapMapper<NftNonce<Self::Api>, BigUint>;
+
+    // Stores the quantity of each NFT or SFT nonce of a claim funded by each depositor, to which the quantity removed from the claim is refunded
+    #[view(viewNftClaimFunding)]
+    #[storage_mapper("nftClaimFunding")]
+    fn nft_claim_funding(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        nft_nonce: &NftNonce<Self::Api>,
+    ) -> MapMapper<ManagedAddress, BigUint>;
+
+    // Stores the total quantity of each NFT or SFT nonce reserved by the claims of all addresses
+    #[view(viewNftReserved)]
+    #[storage_mapper("nftReserved")]
+    fn nft_reserved(&self, token: &TokenIdentifier, nonce: u64) -> SingleValueMapper<BigUint>;
 
     // Stores the last timestamp at which the claim has been modified by the owner for each address and claim type
     #[view(viewClaimModifyDate)]
@@ -58,16 +422,508 @@ pub trait StorageModule {
         claim_type: &ClaimType,
     ) -> SingleValueMapper<u64>;
 
+    // Stores the vesting schedule of the claim for each address, claim type and token. Empty if the claim is not vested
+    #[view(viewVestingSchedule)]
+    #[storage_mapper("vestingSchedule")]
+    fn vesting_schedule(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<VestingSchedule<Self::Api>>;
+
+    // Stores the expiry of the claim for each address and claim type. Empty if the claim does not expire
+    #[view(viewClaimExpiry)]
+    #[storage_mapper("claimExpiry")]
+    fn claim_expiry(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) -> SingleValueMapper<ClaimExpiry<Self::Api>>;
+
+    // Stores the address to which the claims of an address are sent when harvesting. Empty if the claims are sent to the address itself
+    #[view(viewBeneficiary)]
+    #[storage_mapper("beneficiary")]
+    fn beneficiary(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the address to which the claims of an address and claim type are sent when harvesting. Takes precedence over the beneficiary set for all claim types
+    #[view(viewClaimTypeBeneficiary)]
+    #[storage_mapper("claimTypeBeneficiary")]
+    fn claim_type_beneficiary(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the nonce that the next relayed claim authorization of an address must be signed with
+    #[view(viewRelayedClaimNonce)]
+    #[storage_mapper("relayedClaimNonce")]
+    fn relayed_claim_nonce(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Stores the total amount of tokens reserved in the smart contract for each token, for claims, distributions and voucher pools
+    #[view(viewTotalReserved)]
+    #[storage_mapper("totalReserved")]
+    fn total_reserved(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // Stores the total amount of tokens reserved in the smart contract for each claim type and token, for claims and distributions
+    #[view(viewTotalReservedByType)]
+    #[storage_mapper("totalReservedByType")]
+    fn total_reserved_by_type(
+        &self,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
     // Stores whether claim harvesting is paused or not
     #[view(isPaused)]
     #[storage_mapper("isPaused")]
     fn is_paused(&self) -> SingleValueMapper<bool>;
 
-    #[view(viewPrivilegedAddresses)]
+    // Stores the claim types whose harvesting is paused, independently of the pause of the whole contract
+    #[view(viewPausedClaimTypes)]
+    #[storage_mapper("pausedClaimTypes")]
+    fn paused_claim_types(&self) -> UnorderedSetMapper<ClaimType>;
+
+    // Stores the admin of the smart contract, which is set to the deployer when the smart contract is deployed
+    #[view(viewAdmin)]
+    #[storage_mapper("admin")]
+    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the address proposed by the admin as the next admin, until it accepts the role
+    #[view(viewPendingAdmin)]
+    #[storage_mapper("pendingAdmin")]
+    fn pending_admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Stores the delay, in seconds, after which the queued admin operations can be executed. Admin operations are executed immediately while it is zero
+    #[view(viewTimelockDelay)]
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    // Stores the identifier of the last admin operation queued in the timelock
+    #[storage_mapper("lastOperationId")]
+    fn last_operation_id(&self) -> SingleValueMapper<u64>;
+
+    // Stores the admin operations queued in the timelock by their identifier
+    #[view(viewQueuedOperations)]
+    #[storage_mapper("queuedOperations")]
+    fn queued_operations(&self) -> MapMapper<u64, QueuedOperation<Self::Api>>;
+
+    // Stores the addresses that have been granted each role
+    #[view(viewRoleMembers)]
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: &Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Stores the amount of each claim funded by each depositor. The part of a claim funded before the ledger was introduced is not included in it and is attributed to the admin
+    #[view(viewClaimFunding)]
+    #[storage_mapper("claimFunding")]
+    fn claim_funding(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> MapMapper<ManagedAddress, BigUint>;
+
+    // Stores the period after each deposit during which the depositors can claw it back. Clawbacks are disabled while it is zero
+    #[view(viewClawbackGracePeriod)]
+    #[storage_mapper("clawbackGracePeriod")]
+    fn clawback_grace_period(&self) -> SingleValueMapper<u64>;
+
+    // Stores the deposits of each depositor to each claim that may still be clawed back, in the order they were made
+    #[view(viewClaimDeposits)]
+    #[storage_mapper("claimDeposits")]
+    fn claim_deposits(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        depositor: &ManagedAddress,
+    ) -> VecMapper<ClaimDeposit<Self::Api>>;
+
+    // Stores the staking contract to which the claims in each token are forwarded by the claimAndStake endpoint
+    #[view(viewStakingContract)]
+    #[storage_mapper("stakingContract")]
+    fn staking_contract(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<StakingContract<Self::Api>>;
+
+    // Stores the whitelist of functions of target contracts to which the harvested claims can be sent through the claimAndExecute endpoint
+    #[view(viewExecuteTargets)]
+    #[storage_mapper("executeTargets")]
+    fn execute_targets(&self) -> UnorderedSetMapper<ExecuteTarget<Self::Api>>;
+
+    // Stores the claim types in which each depositor can add claims. A depositor without claim types can add claims of any claim type
+    #[view(viewDepositorClaimTypes)]
+    #[storage_mapper("depositorClaimTypes")]
+    fn depositor_claim_types(&self, depositor: &ManagedAddress) -> UnorderedSetMapper<ClaimType>;
+
+    // Stores the quota of each depositor for each token. A depositor without a quota can add any amount of the token to claims
+    #[view(viewDepositorQuota)]
+    #[storage_mapper("depositorQuota")]
+    fn depositor_quota(
+        &self,
+        depositor: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<DepositorQuota<Self::Api>>;
+
+    // Stores the amount of each token that each depositor has added to claims against its quota
+    #[storage_mapper("depositorQuotaUsage")]
+    fn depositor_quota_usage(
+        &self,
+        depositor: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<DepositorQuotaUsage<Self::Api>>;
+
+    // Stores the privileged addresses set before roles were introduced, which are migrated to roles when upgrading
     #[storage_mapper("privilegedAddresses")]
     fn privileged_addresses(&self) -> SetMapper<ManagedAddress>;
 
-    #[view(viewDepositorAddresses)]
+    // Stores the depositor addresses set before roles were introduced, which are migrated to roles when upgrading
     #[storage_mapper("depositorAddresses")]
     fn depositor_addresses(&self) -> SetMapper<ManagedAddress>;
+
+    // Stores the identifier of the last distribution created
+    #[view(viewLastDistributionId)]
+    #[storage_mapper("lastDistributionId")]
+    fn last_distribution_id(&self) -> SingleValueMapper<u64>;
+
+    // Stores each distribution by its identifier
+    #[view(viewDistribution)]
+    #[storage_mapper("distribution")]
+    fn distribution(&self, distribution_id: u64) -> SingleValueMapper<Distribution<Self::Api>>;
+
+    // Stores the bitmap of harvested leaves of each distribution, split in words of 64 leaves
+    #[storage_mapper("distributionClaimedLeaves")]
+    fn distribution_claimed_leaves(
+        &self,
+        distribution_id: u64,
+        word_index: u64,
+    ) -> SingleValueMapper<u64>;
+
+    // Stores the ed25519 public key whose signatures are accepted for vouchers
+    #[view(viewVoucherSigner)]
+    #[storage_mapper("voucherSigner")]
+    fn voucher_signer(&self) -> SingleValueMapper<ManagedByteArray<32>>;
+
+    // Stores the amount of tokens available to pay vouchers for each token
+    #[view(viewVoucherPool)]
+    #[storage_mapper("voucherPool")]
+    fn voucher_pool(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // Stores whether a voucher nonce has already been used by an address
+    #[view(isVoucherNonceUsed)]
+    #[storage_mapper("voucherNonceUsed")]
+    fn voucher_nonce_used(&self, address: &ManagedAddress, nonce: u64) -> SingleValueMapper<bool>;
+
+    // Stores the identifier of the last campaign created
+    #[view(viewLastCampaignId)]
+    #[storage_mapper("lastCampaignId")]
+    fn last_campaign_id(&self) -> SingleValueMapper<u64>;
+
+    // Stores each campaign by its identifier
+    #[view(viewCampaign)]
+    #[storage_mapper("campaign")]
+    fn campaign(&self, campaign_id: u64) -> SingleValueMapper<Campaign<Self::Api>>;
+
+    // Stores the amount of each claim labeled with each campaign
+    #[view(viewClaimCampaigns)]
+    #[storage_mapper("claimCampaigns")]
+    fn claim_campaigns(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> MapMapper<u64, BigUint>;
+
+    // Stores the registry of the addresses that have non-zero claims. The claims left unchanged since before the registry was introduced are only included once indexed by the admin
+    #[storage_mapper("claimants")]
+    fn claimants(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Stores the claim types, tokens and nonces of the non-zero claims of each address in the claimant registry, including its NFT and SFT claims
+    #[storage_mapper("claimantClaims")]
+    fn claimant_claims(&self, address: &ManagedAddress) -> UnorderedSetMapper<ClaimKey<Self::Api>>;
+
+    // Checks whether a token is the token that was used for claims before several tokens could be whitelisted
+    fn is_legacy_claim_token(&self, token: &EgldOrEsdtTokenIdentifier) -> bool {
+        !self.claim_token().is_empty()
+            && token == &EgldOrEsdtTokenIdentifier::esdt(self.claim_token().get())
+    }
+
+    // Returns the amount available to claim for an address, claim type and token, including the legacy claim when the token is the legacy claim token
+    fn get_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        let claim = self.claim(address, claim_type, token).get();
+        if self.is_legacy_claim_token(token) {
+            return claim + self.legacy_claim(address, claim_type).get();
+        }
+
+        claim
+    }
+
+    // Sets the amount of a claim, keeping the claimant registry up to date. The amount includes the legacy claim, which is therefore cleared.
+    // The vesting schedule of a claim reduced to zero is cleared, so that it does not lock the claims added later in the same token
+    fn set_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let claim_key = ClaimKey {
+            claim_type: *claim_type,
+            token: token.clone(),
+            nonce: 0,
+        };
+        // The legacy claim is moved to the claim storage, its amount being added to the total reserved for the claim type, which did not include it
+        if self.is_legacy_claim_token(token) {
+            let legacy_claim = self.legacy_claim(address, claim_type);
+            if !legacy_claim.is_empty() {
+                self.total_reserved_by_type(claim_type, token)
+                    .update(|total| *total += legacy_claim.get());
+                legacy_claim.clear();
+            }
+        }
+        if amount == BigUint::zero() {
+            self.claim(address, claim_type, token).clear();
+            self.vesting_schedule(address, claim_type, token).clear();
+            self.unregister_claimant_claim(address, &claim_key);
+            self.clear_empty_claim_expiry(address, claim_type);
+            return;
+        }
+
+        self.claim(address, claim_type, token).set(&amount);
+        self.register_claimant_claim(address, claim_key);
+    }
+
+    // Sets the quantity of an NFT or SFT nonce reserved by a claim, keeping the claimant registry up to date
+    fn set_nft_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        nft_nonce: &NftNonce<Self::Api>,
+        amount: BigUint,
+    ) {
+        let claim_key = ClaimKey {
+            claim_type: *claim_type,
+            token: EgldOrEsdtTokenIdentifier::esdt(nft_nonce.token.clone()),
+            nonce: nft_nonce.nonce,
+        };
+        if amount == BigUint::zero() {
+            self.nft_claims(address, claim_type).remove(nft_nonce);
+            self.unregister_claimant_claim(address, &claim_key);
+            self.clear_empty_claim_expiry(address, claim_type);
+            return;
+        }
+
+        self.nft_claims(address, claim_type)
+            .insert(nft_nonce.clone(), amount);
+        self.register_claimant_claim(address, claim_key);
+    }
+
+    // Adds a non-zero claim of an address to the claimant registry
+    fn register_claimant_claim(&self, address: &ManagedAddress, claim_key: ClaimKey<Self::Api>) {
+        self.claimant_claims(address).insert(claim_key);
+        self.claimants().insert(address.clone());
+    }
+
+    // Removes a claim reduced to zero from the claimant registry, together with its address once it has no claims left
+    fn unregister_claimant_claim(&self, address: &ManagedAddress, claim_key: &ClaimKey<Self::Api>) {
+        let mut claimant_claims = self.claimant_claims(address);
+        claimant_claims.swap_remove(claim_key);
+        if claimant_claims.is_empty() {
+            self.claimants().swap_remove(address);
+        }
+    }
+
+    // Returns the tokens in which an address may have a claim of a claim type: the tokens of its claims in the claimant registry, the whitelisted tokens and the legacy claim token
+    fn claim_type_tokens(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) -> ManagedVec<EgldOrEsdtTokenIdentifier> {
+        let mut tokens = ManagedVec::new();
+        for claim_key in self.claimant_claims(address).iter() {
+            if &claim_key.claim_type == claim_type
+                && claim_key.nonce == 0
+                && !tokens.contains(&claim_key.token)
+            {
+                tokens.push(claim_key.token);
+            }
+        }
+        for token in self.claim_tokens().iter() {
+            if !tokens.contains(&token) {
+                tokens.push(token);
+            }
+        }
+        if !self.claim_token().is_empty() {
+            let legacy_token = EgldOrEsdtTokenIdentifier::esdt(self.claim_token().get());
+            if !tokens.contains(&legacy_token) {
+                tokens.push(legacy_token);
+            }
+        }
+
+        tokens
+    }
+
+    // Clears the expiry of the claims of an address and claim type once none of its claims, in any token or NFT and SFT nonce, is left,
+    // so that an expiry does not keep restricting the claims added later for the address and claim type
+    fn clear_empty_claim_expiry(&self, address: &ManagedAddress, claim_type: &ClaimType) {
+        let claim_expiry = self.claim_expiry(address, claim_type);
+        if claim_expiry.is_empty()
+            || !self.nft_claims(address, claim_type).is_empty()
+            || !self.legacy_claim(address, claim_type).is_empty()
+        {
+            return;
+        }
+        for claim_key in self.claimant_claims(address).iter() {
+            if &claim_key.claim_type == claim_type {
+                return;
+            }
+        }
+        for token in self.claim_tokens().iter() {
+            if !self.claim(address, claim_type, &token).is_empty() {
+                return;
+            }
+        }
+
+        claim_expiry.clear();
+    }
+
+    // Stores the number of the latest movements kept in the claim history of each address. The claim history is not recorded while it is zero
+    #[view(viewClaimHistoryLimit)]
+    #[storage_mapper("claimHistoryLimit")]
+    fn claim_history_limit(&self) -> SingleValueMapper<u64>;
+
+    // Stores the number of movements recorded in the claim history of each address, including the ones no longer kept
+    #[view(viewClaimHistoryLength)]
+    #[storage_mapper("claimHistoryLength")]
+    fn claim_history_length(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Stores the movements recorded in the claim history of each address by their index, starting from 1
+    #[storage_mapper("claimHistoryEntry")]
+    fn claim_history_entry(
+        &self,
+        address: &ManagedAddress,
+        index: u64,
+    ) -> SingleValueMapper<ClaimHistoryEntry<Self::Api>>;
+
+    // Records a movement of the fungible claims of an address in its claim history
+    fn record_claim_movement(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+        kind: ClaimMovementKind,
+    ) {
+        self.record_claim_movement_of_nonce(address, claim_type, token, 0, amount, kind);
+    }
+
+    // Records a movement of the NFT or SFT claims of an address in its claim history
+    fn record_nft_claim_movement(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        nft_nonce: &NftNonce<Self::Api>,
+        amount: &BigUint,
+        kind: ClaimMovementKind,
+    ) {
+        self.record_claim_movement_of_nonce(
+            address,
+            claim_type,
+            &EgldOrEsdtTokenIdentifier::esdt(nft_nonce.token.clone()),
+            nft_nonce.nonce,
+            amount,
+            kind,
+        );
+    }
+
+    // Records a movement of the claims of an address in its claim history, if enabled, dropping the oldest movements once the limit is reached. The caller is recorded as the operator of the movement.
+    // The movements kept under a higher previous limit are dropped as well, walking back from the oldest movement no longer kept until an already dropped one
+    fn record_claim_movement_of_nonce(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+        nonce: u64,
+        amount: &BigUint,
+        kind: ClaimMovementKind,
+    ) {
+        let limit = self.claim_history_limit().get();
+        if limit == 0 {
+            return;
+        }
+
+        let index = self.claim_history_length(address).get() + 1;
+        self.claim_history_length(address).set(index);
+        self.claim_history_entry(address, index)
+            .set(ClaimHistoryEntry {
+                claim_type: *claim_type,
+                token: token.clone(),
+                nonce,
+                amount: amount.clone(),
+                operator: self.blockchain().get_caller(),
+                timestamp: self.blockchain().get_block_timestamp(),
+                kind,
+            });
+
+        let mut dropped = index.saturating_sub(limit);
+        while dropped > 0 && !self.claim_history_entry(address, dropped).is_empty() {
+            self.claim_history_entry(address, dropped).clear();
+            dropped -= 1;
+        }
+    }
+
+    // Increases the total quantity reserved of an NFT or SFT nonce whenever it is reserved by a claim
+    fn increase_nft_reserved(&self, nft_nonce: &NftNonce<Self::Api>, amount: &BigUint) {
+        self.nft_reserved(&nft_nonce.token, nft_nonce.nonce)
+            .update(|total| *total += amount);
+    }
+
+    // Decreases the total quantity reserved of an NFT or SFT nonce whenever it leaves the smart contract. Panics if the quantity exceeds the total
+    fn decrease_nft_reserved(&self, nft_nonce: &NftNonce<Self::Api>, amount: &BigUint) {
+        self.nft_reserved(&nft_nonce.token, nft_nonce.nonce)
+            .update(|total| {
+                require!(*total >= *amount, ERR_RESERVED_TOTAL_EXCEEDED);
+                *total -= amount;
+            });
+    }
+
+    // Increases the totals of reserved tokens whenever tokens are reserved, the claim type being empty for the voucher pools
+    fn increase_reserved(
+        &self,
+        claim_type: Option<&ClaimType>,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.total_reserved(token).update(|total| *total += amount);
+        if let Some(claim_type) = claim_type {
+            self.total_reserved_by_type(claim_type, token)
+                .update(|total| *total += amount);
+        }
+    }
+
+    // Decreases the totals of reserved tokens whenever reserved tokens leave the smart contract. Panics if an amount exceeds a total, as the totals include every reserved token
+    fn decrease_reserved(
+        &self,
+        claim_type: Option<&ClaimType>,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.total_reserved(token).update(|total| {
+            require!(*total >= *amount, ERR_RESERVED_TOTAL_EXCEEDED);
+            *total -= amount;
+        });
+        if let Some(claim_type) = claim_type {
+            self.total_reserved_by_type(claim_type, token)
+                .update(|total| {
+                    require!(*total >= *amount, ERR_RESERVED_TOTAL_EXCEEDED);
+                    *total -= amount;
+                });
+        }
+    }
 }
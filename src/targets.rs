@@ -0,0 +1,121 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    admin, campaigns,
+    constants::*,
+    depositors, events, requirements,
+    storage::{self, AdminOperation, ExecuteTarget, HarvestedClaim},
+    views,
+};
+
+// Module that handles the whitelist of target contract functions to which harvested claims can be sent through the claimAndExecute endpoint
+#[multiversx_sc::module]
+pub trait TargetsModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + admin::AdminModule
+    + campaigns::CampaignsModule
+    + depositors::DepositorsModule
+{
+    // Endpoint available for the admin of the smart contract to whitelist a function of a target contract to which the harvested claims can be sent through the claimAndExecute endpoint.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(addExecuteTarget)]
+    fn add_execute_target(&self, target: ManagedAddress, function: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        require!(
+            self.blockchain().is_smart_contract(&target),
+            ERR_NOT_A_SMART_CONTRACT
+        );
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::AddExecuteTarget {
+                address: target,
+                function,
+            });
+            return;
+        }
+        self.execute_add_execute_target(target, function);
+    }
+
+    // Whitelists a function of a target contract, once the admin operation is executed
+    fn execute_add_execute_target(&self, target: ManagedAddress, function: ManagedBuffer) {
+        require!(
+            self.execute_targets().insert(ExecuteTarget {
+                address: target.clone(),
+                function: function.clone(),
+            }),
+            ERR_EXECUTE_TARGET_WHITELISTED
+        );
+        self.execute_target_added_event(&target, &function);
+    }
+
+    // Endpoint available for the admin of the smart contract to remove a function of a target contract from the whitelist of the claimAndExecute endpoint.
+    #[endpoint(removeExecuteTarget)]
+    fn remove_execute_target(&self, target: ManagedAddress, function: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        require!(
+            self.execute_targets().swap_remove(&ExecuteTarget {
+                address: target.clone(),
+                function: function.clone(),
+            }),
+            ERR_EXECUTE_TARGET_NOT_WHITELISTED
+        );
+        self.execute_target_removed_event(&target, &function);
+    }
+
+    // Sends the tokens harvested from the claims of an address to a whitelisted function of a target contract. The claims are restored by the callback if the call fails
+    fn execute_harvested_claims(
+        &self,
+        address: &ManagedAddress,
+        target: ManagedAddress,
+        function: ManagedBuffer,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        args: MultiValueEncoded<ManagedBuffer>,
+        harvested_claims: ManagedVec<HarvestedClaim<Self::Api>>,
+    ) -> ! {
+        self.send()
+            .contract_call::<()>(target.clone(), function.clone())
+            .with_egld_or_single_esdt_transfer((token.clone(), 0, amount.clone()))
+            .with_raw_arguments(args.to_arg_buffer())
+            .async_call()
+            .with_callback(self.callbacks().execute_callback(
+                address.clone(),
+                target,
+                function,
+                token.clone(),
+                amount,
+                harvested_claims,
+            ))
+            .call_and_exit()
+    }
+
+    // Callback of the call of a target contract, which restores the harvested tokens to the claims they were taken from if the call failed,
+    // attributing them again to the depositors that funded them and to the campaigns for which they were added
+    #[callback]
+    fn execute_callback(
+        &self,
+        address: ManagedAddress,
+        target: ManagedAddress,
+        function: ManagedBuffer,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        harvested_claims: ManagedVec<HarvestedClaim<Self::Api>>,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.claim_executed_event(&address, &target, &function, &token, &amount);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.restore_harvested_claims(&address, &token, &harvested_claims);
+                self.claim_execute_failed_event(&address, &target, &function, &token, &amount);
+            }
+        }
+    }
+}
@@ -1,42 +1,338 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::storage::{self, ClaimType, Len};
+use crate::storage::{self, ClaimHistoryEntry, ClaimType, NftNonce, Role};
 
-// Structure that is used in order to return claims with their last modification timestamp
+// Structure that is used in order to return claims with their claim type and last modification timestamp
 #[derive(ManagedVecItem, Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct Claim<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
     pub amount: BigUint<M>,
     pub date: u64,
 }
 
+// Structure that is used in order to return claims split into the vested (harvestable) and locked amounts, with their last modification timestamp
+#[derive(ManagedVecItem, Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct VestedClaim<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
+    pub amount: BigUint<M>,
+    pub vested: BigUint<M>,
+    pub locked: BigUint<M>,
+    pub date: u64,
+}
+
+// Structure that is used in order to return, for a whitelisted token, the amount reserved by the smart contract compared to its balance
+#[derive(ManagedVecItem, Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct Solvency<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub reserved: BigUint<M>,
+    pub balance: BigUint<M>,
+    pub solvent: bool,
+}
+
+// Structure that is used in order to return the amount of a claim of an address in the claimant registry with its claim type, token and nonce, which is zero for the fungible claims
+#[derive(ManagedVecItem, Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct ClaimantClaim<M: ManagedTypeApi> {
+    pub claim_type: ClaimType,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+}
+
+// Structure that is used in order to return an address of the claimant registry with its non-zero claims
+#[derive(Clone, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct ClaimantClaims<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub claims: ManagedVec<M, ClaimantClaim<M>>,
+}
+
 // Module that implements views, by which we understand read-only endpoints
 #[multiversx_sc::module]
 pub trait ViewsModule: storage::StorageModule {
-    //View that returns the sum of all claims, from all claim types, for a given address
+    // View that returns the amount available to claim for a given address, claim type and token
+    #[view(viewClaim)]
+    fn view_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        self.get_claim(address, claim_type, token)
+    }
+
+    //View that returns the sum of all claims, from all claim types, for a given address and token
     #[view(viewClaims)]
-    fn view_claims(&self, address: &ManagedAddress) -> BigUint {
+    fn view_claims(&self, address: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
         let mut claim = BigUint::zero();
-        for claim_type in 0..ClaimType::len() + 1 {
-            claim += self.claim(address, &ClaimType::from(claim_type)).get();
+        for claim_type in self.claim_types().keys() {
+            claim += self.get_claim(address, &claim_type, token);
         }
 
         claim
     }
 
-    // View that returns all claims with the last timestamp at which the claims have been modified by the owner for a given address
+    // View that returns all claims in a given token with the last timestamp at which the claims have been modified by the owner for a given address
     #[view(viewClaimWithDate)]
-    fn view_claims_with_date(&self, address: &ManagedAddress) -> ManagedVec<Claim<Self::Api>> {
+    fn view_claims_with_date(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedVec<Claim<Self::Api>> {
         let mut claims = ManagedVec::new();
-        for claim_type in 0..ClaimType::len() + 1 {
+        for claim_type in self.claim_types().keys() {
             claims.push(Claim {
-                amount: self.claim(address, &ClaimType::from(claim_type)).get(),
-                date: self
-                    .claim_modify_date(address, &ClaimType::from(claim_type))
-                    .get(),
+                claim_type,
+                amount: self.get_claim(address, &claim_type, token),
+                date: self.claim_modify_date(address, &claim_type).get(),
+            });
+        }
+
+        claims
+    }
+
+    // View that returns the amount of tokens of a claim that are still locked by its vesting schedule for a given address, claim type and token
+    #[view(viewLockedClaim)]
+    fn view_locked_claim(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        let vesting_schedule = self.vesting_schedule(address, claim_type, token);
+        if vesting_schedule.is_empty() {
+            return BigUint::zero();
+        }
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        let locked = vesting_schedule.get().locked_amount(timestamp);
+        let claim = self.get_claim(address, claim_type, token);
+        // Tokens removed from a vested claim are taken from its unlocked part first, so the locked amount is capped by the claim
+        if locked > claim {
+            claim
+        } else {
+            locked
+        }
+    }
+
+    // View that returns whether the claim of a given address and claim type has expired and can no longer be harvested
+    #[view(isClaimExpired)]
+    fn is_claim_expired(&self, address: &ManagedAddress, claim_type: &ClaimType) -> bool {
+        let claim_expiry = self.claim_expiry(address, claim_type);
+        !claim_expiry.is_empty()
+            && claim_expiry.get().timestamp <= self.blockchain().get_block_timestamp()
+    }
+
+    // View that returns all claims in a given token split into vested and locked amounts, with the last timestamp at which the claims have been modified for a given address
+    #[view(viewClaimsWithVesting)]
+    fn view_claims_with_vesting(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedVec<VestedClaim<Self::Api>> {
+        let mut claims = ManagedVec::new();
+        for claim_type in self.claim_types().keys() {
+            let amount = self.get_claim(address, &claim_type, token);
+            let locked = self.view_locked_claim(address, &claim_type, token);
+            claims.push(VestedClaim {
+                claim_type,
+                vested: &amount - &locked,
+                amount,
+                locked,
+                date: self.claim_modify_date(address, &claim_type).get(),
             });
         }
 
         claims
     }
+
+    // View that returns the address to which the claims of a given address and claim type are sent when harvesting
+    #[view(viewClaimReceiver)]
+    fn view_claim_receiver(
+        &self,
+        address: &ManagedAddress,
+        claim_type: &ClaimType,
+    ) -> ManagedAddress {
+        let claim_type_beneficiary = self.claim_type_beneficiary(address, claim_type);
+        if !claim_type_beneficiary.is_empty() {
+            return claim_type_beneficiary.get();
+        }
+
+        let beneficiary = self.beneficiary(address);
+        if !beneficiary.is_empty() {
+            return beneficiary.get();
+        }
+
+        address.clone()
+    }
+
+    // View that returns the EGLD claims of a given address for each claim type, split into vested and locked amounts
+    #[view(viewEgldClaims)]
+    fn view_egld_claims(&self, address: &ManagedAddress) -> ManagedVec<VestedClaim<Self::Api>> {
+        self.view_claims_with_vesting(address, &EgldOrEsdtTokenIdentifier::egld())
+    }
+
+    // View that returns the ESDT claims of a given address for each whitelisted ESDT token and claim type, split into vested and locked amounts
+    #[view(viewEsdtClaims)]
+    fn view_esdt_claims(
+        &self,
+        address: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<TokenIdentifier, ManagedVec<VestedClaim<Self::Api>>>> {
+        let mut claims = MultiValueEncoded::new();
+        for token in self.claim_tokens().iter() {
+            if token.is_egld() {
+                continue;
+            }
+
+            let token_claims = self.view_claims_with_vesting(address, &token);
+            claims.push((token.unwrap_esdt(), token_claims).into());
+        }
+
+        claims
+    }
+
+    // View that returns the NFT and SFT nonces reserved for a given address, with their claim type and quantity
+    #[view(viewNftClaims)]
+    fn view_nft_claims(
+        &self,
+        address: &ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ClaimType, EsdtTokenPayment>> {
+        let mut claims = MultiValueEncoded::new();
+        for claim_type in self.claim_types().keys() {
+            for (nft_nonce, amount) in self.nft_claims(address, &claim_type).iter() {
+                claims.push(
+                    (
+                        claim_type,
+                        EsdtTokenPayment::new(nft_nonce.token, nft_nonce.nonce, amount),
+                    )
+                        .into(),
+                );
+            }
+        }
+
+        claims
+    }
+
+    // View that returns whether the leaf with the given index of a distribution has already been harvested
+    #[view(isDistributionLeafClaimed)]
+    fn is_distribution_leaf_claimed(&self, distribution_id: u64, index: u64) -> bool {
+        let word = self
+            .distribution_claimed_leaves(distribution_id, index / 64)
+            .get();
+        word & (1u64 << (index % 64)) != 0
+    }
+
+    // View that returns, for each whitelisted token, the total amount reserved for claims, distributions and voucher pools compared to the balance of the smart contract. The smart contract is solvent for a token when its balance covers the reserved amount.
+    #[view(viewSolvency)]
+    fn view_solvency(&self) -> ManagedVec<Solvency<Self::Api>> {
+        let mut solvency = ManagedVec::new();
+        for token in self.claim_tokens().iter() {
+            let reserved = self.total_reserved(&token).get();
+            let balance = self.blockchain().get_sc_balance(&token, 0);
+            let solvent = balance >= reserved;
+            solvency.push(Solvency {
+                token,
+                reserved,
+                balance,
+                solvent,
+            });
+        }
+
+        solvency
+    }
+
+    // View that returns whether an address has a role, either because it has been granted the role or because it is the admin of the smart contract
+    #[view(hasRole)]
+    fn has_role(&self, role: &Role, address: &ManagedAddress) -> bool {
+        &self.admin().get() == address || self.role_members(role).contains(address)
+    }
+
+    // View that returns the roles that have been granted to an address
+    #[view(viewAddressRoles)]
+    fn view_address_roles(&self, address: &ManagedAddress) -> MultiValueEncoded<Role> {
+        let mut roles = MultiValueEncoded::new();
+        for role in [
+            Role::Pauser,
+            Role::Depositor,
+            Role::ClaimRemover,
+            Role::Unpauser,
+            Role::Admin,
+        ] {
+            if self.role_members(&role).contains(address) {
+                roles.push(role);
+            }
+        }
+
+        roles
+    }
+
+    // View that returns the number of addresses in the claimant registry
+    #[view(viewClaimantsCount)]
+    fn view_claimants_count(&self) -> usize {
+        self.claimants().len()
+    }
+
+    // View that returns a page of the addresses in the claimant registry with the amount of each of their non-zero claims, skipping the given number of addresses.
+    // The order of the addresses changes whenever an address leaves the registry, so the pages should be retrieved at the same block. The NFT and SFT claims are returned with their nonce
+    #[view(viewClaimants)]
+    fn view_claimants(
+        &self,
+        offset: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ClaimantClaims<Self::Api>> {
+        let mut claimants = MultiValueEncoded::new();
+        let claimants_count = self.claimants().len();
+        let mut index = offset.saturating_add(1);
+        while index <= claimants_count && index <= offset.saturating_add(size) {
+            let address = self.claimants().get_by_index(index);
+            let mut claims = ManagedVec::new();
+            for claim_key in self.claimant_claims(&address).iter() {
+                let amount = if claim_key.nonce == 0 {
+                    self.get_claim(&address, &claim_key.claim_type, &claim_key.token)
+                } else {
+                    let nft_nonce = NftNonce {
+                        token: claim_key.token.clone().unwrap_esdt(),
+                        nonce: claim_key.nonce,
+                    };
+                    self.nft_claims(&address, &claim_key.claim_type)
+                        .get(&nft_nonce)
+                        .unwrap_or_default()
+                };
+                claims.push(ClaimantClaim {
+                    claim_type: claim_key.claim_type,
+                    token: claim_key.token,
+                    nonce: claim_key.nonce,
+                    amount,
+                });
+            }
+            claimants.push(ClaimantClaims { address, claims });
+            index += 1;
+        }
+
+        claimants
+    }
+
+    // View that returns a page of the claim history of an address, from the latest movement to the oldest one kept, skipping the given number of movements
+    #[view(viewClaimHistory)]
+    fn view_claim_history(
+        &self,
+        address: &ManagedAddress,
+        offset: u64,
+        size: u64,
+    ) -> MultiValueEncoded<ClaimHistoryEntry<Self::Api>> {
+        let mut entries = MultiValueEncoded::new();
+        let length = self.claim_history_length(address).get();
+        let limit = self.claim_history_limit().get();
+        let kept = if length < limit { length } else { limit };
+        let mut index = length.saturating_sub(offset);
+        while index > length - kept && entries.len() < size as usize {
+            let entry = self.claim_history_entry(address, index);
+            if entry.is_empty() {
+                break;
+            }
+            entries.push(entry.get());
+            index -= 1;
+        }
+
+        entries
+    }
 }
@@ -0,0 +1,189 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    admin, campaigns,
+    constants::*,
+    depositors, events, requirements,
+    storage::{self, AdminOperation, ClaimMovementKind, ClaimType},
+    views,
+};
+
+// Structure that defines the content of a voucher signed off-chain by the voucher signer
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, TypeAbi)]
+pub struct Voucher<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub claim_type: ClaimType,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub nonce: u64,
+    pub deadline: u64,
+}
+
+// Module that handles vouchers, which are claims signed off-chain by a signer registered by a privileged address and paid from a pool funded in advance
+#[multiversx_sc::module]
+pub trait VouchersModule:
+    storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + requirements::RequirementsModule
+    + admin::AdminModule
+    + campaigns::CampaignsModule
+    + depositors::DepositorsModule
+{
+    // Endpoint available for privileged addresses of the smart contract to register the ed25519 public key whose signatures are accepted for vouchers.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(setVoucherSigner)]
+    fn set_voucher_signer(&self, signer: ManagedByteArray<32>) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_privileged(&caller);
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::SetVoucherSigner { signer });
+            return;
+        }
+        self.execute_set_voucher_signer(signer);
+    }
+
+    // Registers the public key whose signatures are accepted for vouchers, once the admin operation is executed
+    fn execute_set_voucher_signer(&self, signer: ManagedByteArray<32>) {
+        let caller = self.blockchain().get_caller();
+        self.voucher_signer().set(&signer);
+        self.voucher_signer_set_event(&caller, &signer);
+    }
+
+    // Endpoint available for privileged addresses and depositors of the smart contract to add tokens to the pool from which vouchers are paid. Each whitelisted token has its own pool.
+    #[payable("*")]
+    #[endpoint(fundVoucherPool)]
+    fn fund_voucher_pool(&self) {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_token_is_whitelisted(&payment_token);
+        self.require_value_not_zero(&payment_amount);
+
+        let caller = self.blockchain().get_caller();
+        self.require_address_has_deposit_rights(&caller);
+        self.use_depositor_quota(&caller, &payment_token, &payment_amount);
+
+        self.voucher_pool(&payment_token)
+            .update(|voucher_pool| *voucher_pool += &payment_amount);
+        self.increase_reserved(None, &payment_token, &payment_amount);
+        self.voucher_pool_funded_event(&caller, &payment_token, &payment_amount);
+    }
+
+    // Endpoint available for the admin of the smart contract to withdraw tokens from the voucher pool of a token.
+    // Queued in the timelock whenever a timelock delay is set.
+    #[endpoint(withdrawVoucherPool)]
+    fn withdraw_voucher_pool(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.require_address_is_admin(&caller);
+        self.require_value_not_zero(&amount);
+
+        if self.is_timelock_enabled() {
+            self.queue_operation(AdminOperation::WithdrawVoucherPool { token, amount });
+            return;
+        }
+        self.execute_withdraw_voucher_pool(token, amount);
+    }
+
+    // Withdraws tokens from the voucher pool of a token and sends them to the admin, once the admin operation is executed
+    fn execute_withdraw_voucher_pool(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        let voucher_pool = self.voucher_pool(&token).get();
+        require!(voucher_pool >= amount, ERR_VOUCHER_POOL_INSUFFICIENT);
+        self.voucher_pool(&token).set(voucher_pool - &amount);
+        self.decrease_reserved(None, &token, &amount);
+        self.voucher_pool_withdrawn_event(&token, &amount);
+
+        let admin = self.admin().get();
+        self.send().direct(&admin, &token, 0, &amount);
+    }
+
+    // Endpoint available for the public to redeem a voucher issued to the calling address. Cannot be called while harvesting is paused.
+    // The signature must be the ed25519 signature of the voucher signer over the address of the smart contract followed by the top encoded voucher.
+    // Each nonce can only be used once per address and the voucher can no longer be redeemed after its deadline.
+    #[endpoint(redeemVoucher)]
+    fn redeem_voucher(
+        &self,
+        claim_type: ClaimType,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        nonce: u64,
+        deadline: u64,
+        signature: ManagedByteArray<64>,
+    ) {
+        require!(!self.is_paused().get(), ERR_CONTRACT_PAUSED);
+        self.require_claim_type_is_registered(&claim_type);
+        self.require_claim_type_not_paused(&claim_type);
+        self.require_value_not_zero(&amount);
+        require!(
+            !self.voucher_signer().is_empty(),
+            ERR_VOUCHER_SIGNER_NOT_SET
+        );
+        require!(
+            deadline >= self.blockchain().get_block_timestamp(),
+            ERR_VOUCHER_EXPIRED
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.voucher_nonce_used(&caller, nonce).get(),
+            ERR_VOUCHER_NONCE_USED
+        );
+
+        let voucher = Voucher {
+            address: caller.clone(),
+            claim_type,
+            token,
+            amount,
+            nonce,
+            deadline,
+        };
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append(&self.serializer().top_encode_to_managed_buffer(&voucher));
+        require!(
+            self.crypto().verify_ed25519(
+                self.voucher_signer().get().as_managed_buffer(),
+                &message,
+                signature.as_managed_buffer()
+            ),
+            ERR_INVALID_SIGNATURE
+        );
+
+        let voucher_pool = self.voucher_pool(&voucher.token).get();
+        require!(
+            voucher_pool >= voucher.amount,
+            ERR_VOUCHER_POOL_INSUFFICIENT
+        );
+        self.voucher_pool(&voucher.token)
+            .set(voucher_pool - &voucher.amount);
+        self.decrease_reserved(None, &voucher.token, &voucher.amount);
+        self.voucher_nonce_used(&caller, nonce).set(true);
+
+        self.voucher_redeemed_event(
+            &caller,
+            &voucher.claim_type,
+            nonce,
+            &voucher.token,
+            &voucher.amount,
+        );
+        self.record_claim_movement(
+            &caller,
+            &voucher.claim_type,
+            &voucher.token,
+            &voucher.amount,
+            ClaimMovementKind::Harvested,
+        );
+        self.claim_collected_event(
+            &caller,
+            &voucher.claim_type,
+            &voucher.token,
+            &voucher.amount,
+        );
+
+        self.send()
+            .direct(&caller, &voucher.token, 0, &voucher.amount);
+    }
+}
@@ -1,17 +1,51 @@
 use claims::*;
-use claims::{constants::*, storage::StorageModule};
+use claims::{
+    admin::AdminModule, campaigns::CampaignsModule, constants::*, depositors::DepositorsModule,
+    distribution::DistributionModule, nfts::NftsModule, roles::RolesModule, staking::StakingModule,
+    storage::ClaimMovementKind, storage::ExecuteTarget, storage::NftNonce, storage::RelayedClaim,
+    storage::Role, storage::StorageModule, targets::TargetsModule, views::ViewsModule,
+    vouchers::Voucher, vouchers::VouchersModule,
+};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
 use multiversx_sc::{
-    codec::multi_types::{MultiValue3, OptionalValue},
-    types::{Address, MultiValueEncoded},
+    codec::multi_types::{MultiValue2, MultiValue3, OptionalValue},
+    contract_base::ContractBase,
+    types::{Address, ManagedBuffer, ManagedByteArray, MultiValueEncoded},
 };
 use multiversx_sc_scenario::testing_framework::{BlockchainStateWrapper, ContractObjWrapper};
 use multiversx_sc_scenario::*;
+use staking_mock::StakingMock;
 
 pub const WASM_PATH: &'static str = "../output/claims.wasm";
+pub const STAKING_MOCK_WASM_PATH: &'static str = "../output/staking-mock.wasm";
 pub const TOKEN_ID: &[u8] = b"ITHEUM-df6f26";
 pub const WRONG_TOKEN_ID: &[u8] = b"WRONG-123456";
+pub const NFT_TOKEN_ID: &[u8] = b"DATANFTFT-e0b917";
+pub const LEGACY_TOKEN_ID: &[u8] = b"ITHEUM-a61317";
 pub const OWNER_EGLD_BALANCE: u64 = 100_000_000;
 
+// Staking contract used as target of the claimAndStake endpoint, which keeps the tokens staked on behalf of the address given as argument
+mod staking_mock {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait StakingMock {
+        #[init]
+        fn init(&self) {}
+
+        #[payable("*")]
+        #[endpoint]
+        fn stake(&self, address: ManagedAddress) {
+            let payment = self.call_value().egld_or_single_esdt();
+            self.staked(&address)
+                .update(|staked| *staked += payment.amount);
+        }
+
+        #[storage_mapper("staked")]
+        fn staked(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    }
+}
+
 struct ContractSetup<ContractObjBuilder>
 where
     ContractObjBuilder: 'static + Copy + Fn() -> claims::ContractObj<DebugApi>,
@@ -55,19 +89,20 @@ where
         .assert_ok();
     blockchain_wrapper
         .execute_tx(&owner_address, &cf_wrapper, &rust_zero, |sc| {
-            sc.set_claim_token(managed_token_id!(TOKEN_ID));
+            sc.add_claim_token(managed_token_id_wrapped!(TOKEN_ID));
         })
         .assert_ok();
 
     blockchain_wrapper
         .execute_tx(&owner_address, &cf_wrapper, &rust_zero, |sc| {
-            sc.add_privileged_address(managed_address!(&first_user_address));
+            sc.grant_role(Role::Pauser, managed_address!(&first_user_address));
+            sc.grant_role(Role::Depositor, managed_address!(&first_user_address));
         })
         .assert_ok();
 
     blockchain_wrapper
         .execute_tx(&owner_address, &cf_wrapper, &rust_zero, |sc| {
-            sc.add_depositor_address(managed_address!(&second_user_address));
+            sc.grant_role(Role::Depositor, managed_address!(&second_user_address));
         })
         .assert_ok();
 
@@ -95,6 +130,13 @@ where
     }
 }
 
+// Returns the ed25519 keypair derived from a secret key filled with the given byte, used to sign messages off-chain
+fn signing_keypair(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
 #[test] //Tests whether the contrat is deployed and initialized correctly after deployment
 fn deploy_test() {
     let mut setup = setup_contract(claims::contract_obj);
@@ -111,6 +153,153 @@ fn deploy_test() {
         .assert_ok();
 }
 
+#[test] //Tests whether the claims stored before several tokens could be whitelisted remain available after an upgrade
+        //Tests whether the legacy claim token is whitelisted on upgrade, its balance being reserved for the legacy claims
+        //Tests whether legacy claims can be harvested and indexed, moving them to the current claim storage
+fn upgrade_legacy_claims_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.third_user_address;
+
+    b_wrapper.set_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        LEGACY_TOKEN_ID,
+        &rust_biguint!(500),
+    );
+
+    // Storage written by the smart contract before several tokens could be whitelisted
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_token().set(&managed_token_id!(LEGACY_TOKEN_ID));
+                sc.legacy_claim(&managed_address!(user_addr), &CLAIM_TYPE_REWARD)
+                    .set(managed_biguint!(200));
+                sc.legacy_claim(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP)
+                    .set(managed_biguint!(300));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+                sc.unpause();
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc
+                .claim_tokens()
+                .contains(&managed_token_id_wrapped!(LEGACY_TOKEN_ID)));
+            assert_eq!(
+                sc.view_claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(LEGACY_TOKEN_ID)
+                ),
+                managed_biguint!(200)
+            );
+            assert_eq!(
+                sc.view_claims(
+                    &managed_address!(user_addr),
+                    &managed_token_id_wrapped!(LEGACY_TOKEN_ID)
+                ),
+                managed_biguint!(500)
+            );
+            assert_eq!(
+                sc.total_reserved(&managed_token_id_wrapped!(LEGACY_TOKEN_ID))
+                    .get(),
+                managed_biguint!(500)
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_surplus(managed_token_id_wrapped!(LEGACY_TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_NO_SURPLUS);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::Some(managed_token_id_wrapped!(LEGACY_TOKEN_ID)),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, LEGACY_TOKEN_ID, &rust_biguint!(200));
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut addresses = MultiValueEncoded::new();
+                addresses.push(managed_address!(user_addr));
+                sc.index_claimants(addresses);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc
+                .legacy_claim(&managed_address!(user_addr), &CLAIM_TYPE_REWARD)
+                .is_empty());
+            assert!(sc
+                .legacy_claim(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP)
+                .is_empty());
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(LEGACY_TOKEN_ID)
+                )
+                .get(),
+                managed_biguint!(300)
+            );
+            assert_eq!(
+                sc.total_reserved(&managed_token_id_wrapped!(LEGACY_TOKEN_ID))
+                    .get(),
+                managed_biguint!(300)
+            );
+            assert_eq!(
+                sc.total_reserved_by_type(
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(LEGACY_TOKEN_ID)
+                )
+                .get(),
+                managed_biguint!(300)
+            );
+            assert_eq!(sc.view_claimants_count(), 1);
+        })
+        .assert_ok();
+}
+
 #[test] //Tests wether pausing and unpausing the contract works correctly
         //Tests wether trying to change the pause state to the already set state returns an error
         //Tests wether privileged addresses can pause harvesting, but normal or depositors addresses cannnot
@@ -241,16 +430,14 @@ fn pause_unpause_test() {
         .assert_ok();
 }
 
-#[test] //Tests wether adding and removing privileged addresses works as expected
-        //Tests if trying to adde more privileged addresses than the max number of privileged addresses returns an error
-        //Tests if trying give privileges to an address that already has them returns an error
-        //Tests if trying to offer privileges to the owner of the smart contract returns an error
-        //Tests if trying to remove the privileges that is not privileged returns an error
-fn add_and_remove_privileged_addresses_test() {
+#[test] //Tests wether granting and revoking roles works as expected
+        //Tests if trying to grant a role to an address that already has it returns an error
+        //Tests if trying to revoke a role from an address that does not have it returns an error
+        //Tests wether only the addresses with the Pauser and Unpauser roles can pause and unpause harvesting
+fn grant_and_revoke_roles_test() {
     let mut setup = setup_contract(claims::contract_obj);
     let b_wrapper = &mut setup.blockchain_wrapper;
     let owner_address = &setup.owner_address;
-    let first_user_addr = &setup.first_user_address;
     let second_user_addr = &setup.second_user_address;
     let third_user_addr = &setup.third_user_address;
 
@@ -260,7 +447,7 @@ fn add_and_remove_privileged_addresses_test() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.add_privileged_address(managed_address!(second_user_addr));
+                sc.grant_role(Role::Pauser, managed_address!(second_user_addr));
             },
         )
         .assert_ok();
@@ -270,39 +457,58 @@ fn add_and_remove_privileged_addresses_test() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.add_privileged_address(managed_address!(third_user_addr));
+                sc.grant_role(Role::Pauser, managed_address!(second_user_addr));
             },
         )
-        .assert_user_error(ERR_MAX_NUMBER_OF_PRIVILEGED_ADDRESSES);
+        .assert_user_error(ERR_ROLE_ALREADY_GRANTED);
     b_wrapper
         .execute_tx(
-            &owner_address,
+            &third_user_addr,
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.remove_privileged_address(managed_address!(third_user_addr));
+                sc.pause();
             },
         )
-        .assert_user_error(ERR_ADDRESS_NOT_PRIVILEGED);
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
     b_wrapper
-        .execute_query(&setup.contract_wrapper, |sc| {
-            assert_eq!(
-                sc.privileged_addresses()
-                    .contains(&managed_address!(first_user_addr))
-                    && sc
-                        .privileged_addresses()
-                        .contains(&managed_address!(second_user_addr)),
-                true
-            );
-        })
+        .execute_tx(
+            &second_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause();
+            },
+        )
         .assert_ok();
+    b_wrapper
+        .execute_tx(
+            &second_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unpause();
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
     b_wrapper
         .execute_tx(
             &owner_address,
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.remove_privileged_address(managed_address!(second_user_addr));
+                sc.grant_role(Role::Unpauser, managed_address!(third_user_addr));
+                sc.revoke_role(Role::Pauser, managed_address!(second_user_addr));
+            },
+        )
+        .assert_ok();
+    b_wrapper
+        .execute_tx(
+            &third_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unpause();
             },
         )
         .assert_ok();
@@ -312,100 +518,108 @@ fn add_and_remove_privileged_addresses_test() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.add_privileged_address(managed_address!(owner_address));
+                sc.revoke_role(Role::Pauser, managed_address!(second_user_addr));
             },
         )
-        .assert_user_error(ERR_OWNER_NOT_PRIVILEGED);
+        .assert_user_error(ERR_ROLE_NOT_GRANTED);
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.privileged_addresses()
-                    .contains(&managed_address!(first_user_addr))
-                    && !sc
-                        .privileged_addresses()
-                        .contains(&managed_address!(second_user_addr)),
+                sc.has_role(&Role::Pauser, &managed_address!(second_user_addr)),
+                false
+            );
+            assert_eq!(
+                sc.has_role(&Role::Depositor, &managed_address!(second_user_addr)),
+                true
+            );
+            assert_eq!(
+                sc.has_role(&Role::Unpauser, &managed_address!(owner_address)),
                 true
             );
+            assert_eq!(sc.role_members(&Role::Unpauser).len(), 1);
         })
         .assert_ok();
 }
 
-#[test] //Tests wether adding and removing depositor addresses works as expected
-        //Tests if trying give depositor to an address that already has them returns an error
-        //Tests if trying to offer depositor to the owner of the smart contract returns an error
-        //Tests if trying to remove the depositor that is not depositor returns an error
-fn add_and_remove_depositor_addresses_test() {
+#[test] //Tests wether addresses with the Admin role can manage all the other roles
+        //Tests if trying to grant the Admin role from an address with the Admin role returns an error
+        //Tests wether an address can renounce its roles
+fn manage_roles_with_admin_role_test() {
     let mut setup = setup_contract(claims::contract_obj);
     let b_wrapper = &mut setup.blockchain_wrapper;
     let owner_address = &setup.owner_address;
-    let first_user_addr = &setup.first_user_address;
     let second_user_addr = &setup.second_user_address;
     let third_user_addr = &setup.third_user_address;
 
     b_wrapper
         .execute_tx(
-            &owner_address,
+            &third_user_addr,
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.add_depositor_address(managed_address!(first_user_addr));
+                sc.grant_role(Role::ClaimRemover, managed_address!(second_user_addr));
             },
         )
-        .assert_ok();
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
     b_wrapper
         .execute_tx(
             &owner_address,
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.remove_depositor_address(managed_address!(third_user_addr));
+                sc.grant_role(Role::Admin, managed_address!(third_user_addr));
             },
         )
-        .assert_user_error(ERR_ADDRESS_NOT_DEPOSITOR);
-    b_wrapper
-        .execute_query(&setup.contract_wrapper, |sc| {
-            assert_eq!(
-                sc.depositor_addresses()
-                    .contains(&managed_address!(first_user_addr))
-                    && sc
-                        .depositor_addresses()
-                        .contains(&managed_address!(second_user_addr)),
-                true
-            );
-        })
         .assert_ok();
     b_wrapper
         .execute_tx(
-            &owner_address,
+            &third_user_addr,
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.remove_depositor_address(managed_address!(first_user_addr));
+                sc.grant_role(Role::ClaimRemover, managed_address!(second_user_addr));
             },
         )
         .assert_ok();
     b_wrapper
         .execute_tx(
-            &owner_address,
+            &third_user_addr,
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.add_depositor_address(managed_address!(owner_address));
+                sc.grant_role(Role::Admin, managed_address!(second_user_addr));
             },
         )
-        .assert_user_error(ERR_OWNER_NOT_DEPOSITOR);
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            assert_eq!(
-                !sc.depositor_addresses()
-                    .contains(&managed_address!(first_user_addr))
-                    && sc
-                        .depositor_addresses()
-                        .contains(&managed_address!(second_user_addr)),
-                true
-            );
+            let roles: Vec<Role> = sc
+                .view_address_roles(&managed_address!(second_user_addr))
+                .into_iter()
+                .collect();
+            assert_eq!(roles, [Role::Depositor, Role::ClaimRemover]);
         })
         .assert_ok();
+    b_wrapper
+        .execute_tx(
+            &second_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.renounce_role(Role::ClaimRemover);
+            },
+        )
+        .assert_ok();
+    b_wrapper
+        .execute_tx(
+            &second_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.renounce_role(Role::ClaimRemover);
+            },
+        )
+        .assert_user_error(ERR_ROLE_NOT_GRANTED);
 }
 
 #[test] //Tests wether adding and removing singular claims works as expected
@@ -425,7 +639,11 @@ fn add_and_remove_claim_test() {
             0,
             &rust_biguint!(1_000_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -433,8 +651,12 @@ fn add_and_remove_claim_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 1_000_000
             );
         })
@@ -448,7 +670,11 @@ fn add_and_remove_claim_test() {
             0,
             &rust_biguint!(0),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_NON_ZERO_VALUE);
@@ -456,8 +682,12 @@ fn add_and_remove_claim_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 1_000_000
             );
         })
@@ -473,7 +703,8 @@ fn add_and_remove_claim_test() {
             |sc| {
                 sc.remove_claim(
                     &managed_address!(user_addr),
-                    storage::ClaimType::Airdrop,
+                    CLAIM_TYPE_AIRDROP,
+                    managed_token_id_wrapped!(TOKEN_ID),
                     managed_biguint!(500_000),
                 );
             },
@@ -483,8 +714,12 @@ fn add_and_remove_claim_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 500_000
             );
         })
@@ -500,7 +735,8 @@ fn add_and_remove_claim_test() {
             |sc| {
                 sc.remove_claim(
                     &managed_address!(user_addr),
-                    storage::ClaimType::Airdrop,
+                    CLAIM_TYPE_AIRDROP,
+                    managed_token_id_wrapped!(TOKEN_ID),
                     managed_biguint!(0),
                 );
             },
@@ -517,7 +753,8 @@ fn add_and_remove_claim_test() {
             |sc| {
                 sc.remove_claim(
                     &managed_address!(user_addr),
-                    storage::ClaimType::Airdrop,
+                    CLAIM_TYPE_AIRDROP,
+                    managed_token_id_wrapped!(TOKEN_ID),
                     managed_biguint!(700_000),
                 );
             },
@@ -527,8 +764,12 @@ fn add_and_remove_claim_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 500_000
             );
         })
@@ -558,7 +799,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(1_000_000),
                     )
                         .into(),
@@ -566,7 +807,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(1_000_000),
                     )
                         .into(),
@@ -581,7 +822,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(first_user_addr),
-                    &storage::ClaimType::Airdrop
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 1_000_000
@@ -594,7 +836,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(second_user_addr),
-                    &storage::ClaimType::Allocation
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 1_000_000
@@ -614,7 +857,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(1_000_000),
                     )
                         .into(),
@@ -622,7 +865,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(1_000_000),
                     )
                         .into(),
@@ -645,7 +888,7 @@ fn add_and_remove_claims_test() {
                     args.push(MultiValue3(
                         (
                             managed_address!(first_user_addr),
-                            storage::ClaimType::Airdrop,
+                            CLAIM_TYPE_AIRDROP,
                             managed_biguint!(1_000),
                         )
                             .into(),
@@ -668,7 +911,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(1_700_000),
                     )
                         .into(),
@@ -676,7 +919,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(0),
                     )
                         .into(),
@@ -691,7 +934,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(first_user_addr),
-                    &storage::ClaimType::Airdrop
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 1_000_000
@@ -704,7 +948,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(second_user_addr),
-                    &storage::ClaimType::Allocation
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 1_000_000
@@ -724,7 +969,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(700_000),
                     )
                         .into(),
@@ -732,12 +977,12 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(500_000),
                     )
                         .into(),
                 ));
-                sc.remove_claims(args);
+                sc.remove_claims(managed_token_id_wrapped!(TOKEN_ID), args);
             },
         )
         .assert_ok();
@@ -747,7 +992,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(first_user_addr),
-                    &storage::ClaimType::Airdrop
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 300_000
@@ -760,7 +1006,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(second_user_addr),
-                    &storage::ClaimType::Allocation
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 500_000
@@ -780,7 +1027,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(200_000),
                     )
                         .into(),
@@ -788,12 +1035,12 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(0),
                     )
                         .into(),
                 ));
-                sc.remove_claims(args);
+                sc.remove_claims(managed_token_id_wrapped!(TOKEN_ID), args);
             },
         )
         .assert_user_error(ERR_NON_ZERO_VALUE);
@@ -810,7 +1057,7 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(400_000),
                     )
                         .into(),
@@ -818,12 +1065,12 @@ fn add_and_remove_claims_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(500_000),
                     )
                         .into(),
                 ));
-                sc.remove_claims(args);
+                sc.remove_claims(managed_token_id_wrapped!(TOKEN_ID), args);
             },
         )
         .assert_user_error(ERR_MORE_THAN_CLAIM);
@@ -841,13 +1088,13 @@ fn add_and_remove_claims_test() {
                     args.push(MultiValue3(
                         (
                             managed_address!(first_user_addr),
-                            storage::ClaimType::Airdrop,
+                            CLAIM_TYPE_AIRDROP,
                             managed_biguint!(1_000),
                         )
                             .into(),
                     ));
                 }
-                sc.remove_claims(args);
+                sc.remove_claims(managed_token_id_wrapped!(TOKEN_ID), args);
             },
         )
         .assert_user_error(ERR_MAX_NUMBER_OF_CLAIMS_PER_OPERATION);
@@ -857,7 +1104,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(first_user_addr),
-                    &storage::ClaimType::Airdrop
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 300_000
@@ -870,7 +1118,8 @@ fn add_and_remove_claims_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(second_user_addr),
-                    &storage::ClaimType::Allocation
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 500_000
@@ -897,7 +1146,11 @@ fn add_claim_privileged_or_depositor_test() {
             0,
             &rust_biguint!(1_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -910,7 +1163,11 @@ fn add_claim_privileged_or_depositor_test() {
             0,
             &rust_biguint!(1_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Royalty);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ROYALTY,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -923,7 +1180,11 @@ fn add_claim_privileged_or_depositor_test() {
             0,
             &rust_biguint!(1_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
@@ -951,7 +1212,7 @@ fn add_claims_privileged_or_depositor_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(600),
                     )
                         .into(),
@@ -959,7 +1220,7 @@ fn add_claims_privileged_or_depositor_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(user_addr_3),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(400),
                     )
                         .into(),
@@ -981,7 +1242,7 @@ fn add_claims_privileged_or_depositor_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(600),
                     )
                         .into(),
@@ -989,7 +1250,7 @@ fn add_claims_privileged_or_depositor_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(user_addr_3),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(400),
                     )
                         .into(),
@@ -1011,7 +1272,7 @@ fn add_claims_privileged_or_depositor_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(600),
                     )
                         .into(),
@@ -1019,7 +1280,7 @@ fn add_claims_privileged_or_depositor_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(user_addr_3),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(400),
                     )
                         .into(),
@@ -1045,16 +1306,24 @@ fn add_claim_wrong_token_test() {
             0,
             &rust_biguint!(1_000_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
-        .assert_user_error(ERR_TOKEN_INCORRECT);
+        .assert_user_error(ERR_TOKEN_NOT_WHITELISTED);
 
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 0
             );
         })
@@ -1081,7 +1350,7 @@ fn add_claims_wrong_token_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(first_user_addr),
-                        storage::ClaimType::Airdrop,
+                        CLAIM_TYPE_AIRDROP,
                         managed_biguint!(200_000),
                     )
                         .into(),
@@ -1089,7 +1358,7 @@ fn add_claims_wrong_token_test() {
                 args.push(MultiValue3(
                     (
                         managed_address!(second_user_addr),
-                        storage::ClaimType::Allocation,
+                        CLAIM_TYPE_ALLOCATION,
                         managed_biguint!(300_000),
                     )
                         .into(),
@@ -1097,14 +1366,15 @@ fn add_claims_wrong_token_test() {
                 sc.add_claims(args);
             },
         )
-        .assert_user_error(ERR_TOKEN_INCORRECT);
+        .assert_user_error(ERR_TOKEN_NOT_WHITELISTED);
 
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
                 sc.claim(
                     &managed_address!(first_user_addr),
-                    &storage::ClaimType::Airdrop
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 0
@@ -1117,7 +1387,8 @@ fn add_claims_wrong_token_test() {
             assert_eq!(
                 sc.claim(
                     &managed_address!(second_user_addr),
-                    &storage::ClaimType::Allocation
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
                 )
                 .get(),
                 0
@@ -1126,8 +1397,8 @@ fn add_claims_wrong_token_test() {
         .assert_ok();
 }
 
-#[test] //Tests whether one can set the claim token only once
-fn reset_claim_token_test() {
+#[test] //Tests whether the owner can add and remove tokens from the whitelist of claim tokens
+fn add_and_remove_claim_token_test() {
     let mut setup = setup_contract(claims::contract_obj);
     let b_wrapper = &mut setup.blockchain_wrapper;
     let owner_address = &setup.owner_address;
@@ -1138,10 +1409,60 @@ fn reset_claim_token_test() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.set_claim_token(managed_token_id!(TOKEN_ID));
+                sc.add_claim_token(managed_token_id_wrapped!(TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_TOKEN_WHITELISTED);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim_token(managed_token_id_wrapped!(WRONG_TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_TOKEN_NOT_WHITELISTED);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(WRONG_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.claim_tokens().len(), 2);
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim_token(managed_token_id_wrapped!(TOKEN_ID));
             },
         )
-        .assert_user_error(ERR_TOKEN_SET);
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(!sc
+                .claim_tokens()
+                .contains(&managed_token_id_wrapped!(TOKEN_ID)));
+            assert!(sc
+                .claim_tokens()
+                .contains(&managed_token_id_wrapped!(WRONG_TOKEN_ID)));
+        })
+        .assert_ok();
 }
 
 #[test] //Tests whether claiming is impossible in pause state
@@ -1170,7 +1491,11 @@ fn harvest_claim_in_pause_test() {
             0,
             &rust_biguint!(0),
             |sc| {
-                sc.harvest_claim(OptionalValue::Some(storage::ClaimType::Airdrop));
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_AIRDROP),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_CONTRACT_PAUSED);
@@ -1191,7 +1516,11 @@ fn harvest_claim_test() {
             0,
             &rust_biguint!(1_000_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1204,7 +1533,11 @@ fn harvest_claim_test() {
             0,
             &rust_biguint!(0),
             |sc| {
-                sc.harvest_claim(OptionalValue::Some(storage::ClaimType::Airdrop));
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_AIRDROP),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1212,8 +1545,12 @@ fn harvest_claim_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 0
             );
         })
@@ -1237,7 +1574,11 @@ fn harvest_wrong_claim_type_test() {
             0,
             &rust_biguint!(1_000_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1250,7 +1591,11 @@ fn harvest_wrong_claim_type_test() {
             0,
             &rust_biguint!(0),
             |sc| {
-                sc.harvest_claim(OptionalValue::Some(storage::ClaimType::Reward));
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_NON_ZERO_VALUE);
@@ -1258,8 +1603,12 @@ fn harvest_wrong_claim_type_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 1_000_000
             );
         })
@@ -1268,8 +1617,12 @@ fn harvest_wrong_claim_type_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Reward)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 0
             );
         })
@@ -1293,7 +1646,11 @@ fn harvest_all_claims_test() {
             0,
             &rust_biguint!(1_000_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Airdrop);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1306,7 +1663,11 @@ fn harvest_all_claims_test() {
             0,
             &rust_biguint!(1_000_000),
             |sc| {
-                sc.add_claim(&managed_address!(user_addr), storage::ClaimType::Reward);
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1319,7 +1680,11 @@ fn harvest_all_claims_test() {
             0,
             &rust_biguint!(0),
             |sc| {
-                sc.harvest_claim(OptionalValue::None);
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1327,8 +1692,12 @@ fn harvest_all_claims_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Airdrop)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 0
             );
         })
@@ -1337,8 +1706,12 @@ fn harvest_all_claims_test() {
     b_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             assert_eq!(
-                sc.claim(&managed_address!(user_addr), &storage::ClaimType::Reward)
-                    .get(),
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
                 0
             );
         })
@@ -1346,3 +1719,4472 @@ fn harvest_all_claims_test() {
 
     b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(2_000_000));
 }
+
+#[test] //Tests whether the owner can register, disable and enable claim types and whether claims can only be added for enabled claim types
+fn add_and_disable_claim_type_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+    let bounty: u8 = 4;
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_type(CLAIM_TYPE_ROYALTY, managed_buffer!(b"Royalty"));
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_REGISTERED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(&managed_address!(user_addr), bounty, OptionalValue::None);
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_NOT_REGISTERED);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_type(bounty, managed_buffer!(b"Bounty"));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(&managed_address!(user_addr), bounty, OptionalValue::None);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_type_enabled(bounty, false);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_type_enabled(bounty, false);
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_ALREADY_DISABLED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(&managed_address!(user_addr), bounty, OptionalValue::None);
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_NOT_ENABLED);
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.claim_types().len(), 5);
+            assert_eq!(
+                sc.view_claims(
+                    &managed_address!(user_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                ),
+                1_000
+            );
+        })
+        .assert_ok();
+
+    // Claims of a disabled claim type can still be harvested
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+}
+
+#[test] //Tests whether the summary views return each registered claim type once and harvesting an unknown claim type fails
+fn view_claims_with_date_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_claims(
+                    &managed_address!(user_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                ),
+                1_000
+            );
+
+            let claims = sc.view_claims_with_date(
+                &managed_address!(user_addr),
+                &managed_token_id_wrapped!(TOKEN_ID),
+            );
+            assert_eq!(claims.len(), 4);
+            for (index, claim) in claims.iter().enumerate() {
+                assert_eq!(claim.claim_type, index as u8);
+            }
+            assert_eq!(claims.get(0).amount, 1_000);
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(4),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_NOT_REGISTERED);
+}
+
+#[test] //Tests whether a paused claim type cannot be harvested and is skipped when harvesting all claim types
+fn pause_claim_type_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    for claim_type in [CLAIM_TYPE_REWARD, CLAIM_TYPE_ROYALTY] {
+        b_wrapper
+            .execute_esdt_transfer(
+                owner_address,
+                &setup.contract_wrapper,
+                TOKEN_ID,
+                0,
+                &rust_biguint!(1_000),
+                |sc| {
+                    sc.add_claim(
+                        &managed_address!(user_addr),
+                        claim_type,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause_claim_type(CLAIM_TYPE_ROYALTY);
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause_claim_type(CLAIM_TYPE_ROYALTY);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause_claim_type(CLAIM_TYPE_ROYALTY);
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_ALREADY_PAUSED);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_ROYALTY),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_PAUSED);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unpause_claim_type(CLAIM_TYPE_ROYALTY);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_ROYALTY),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(2_000));
+}
+
+#[test] //Tests whether claims in different whitelisted tokens are harvested together or one token at a time
+fn harvest_multiple_tokens_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(WRONG_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            WRONG_TOKEN_ID,
+            0,
+            &rust_biguint!(2_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            WRONG_TOKEN_ID,
+            0,
+            &rust_biguint!(3_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(WRONG_TOKEN_ID)
+                )
+                .get(),
+                3_000
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::Some(managed_token_id_wrapped!(TOKEN_ID)),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+    b_wrapper.check_esdt_balance(user_addr, WRONG_TOKEN_ID, &rust_biguint!(0));
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::Some(managed_token_id_wrapped!(TOKEN_ID)),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NON_ZERO_VALUE);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, WRONG_TOKEN_ID, &rust_biguint!(5_000));
+}
+
+#[test] //Tests whether EGLD claims can be added and are harvested together with ESDT claims
+fn harvest_egld_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_TOKEN_NOT_WHITELISTED);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_egld_token_id!());
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(2_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let egld_claims = sc.view_egld_claims(&managed_address!(user_addr));
+            assert_eq!(egld_claims.get(0).amount, 1_000);
+            assert_eq!(egld_claims.get(1).amount, 0);
+
+            let mut esdt_claims = sc
+                .view_esdt_claims(&managed_address!(user_addr))
+                .into_iter();
+            let (token, token_claims) = esdt_claims.next().unwrap().into_tuple();
+            assert_eq!(token, managed_token_id!(TOKEN_ID));
+            assert_eq!(token_claims.get(0).amount, 2_000);
+            assert!(esdt_claims.next().is_none());
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_egld_balance(user_addr, &rust_biguint!(1_000));
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(2_000));
+    b_wrapper.check_egld_balance(owner_address, &rust_biguint!(OWNER_EGLD_BALANCE - 1_000));
+}
+
+#[test] //Tests whether NFT and SFT nonces can be reserved, removed and harvested together with ESDT claims
+fn add_remove_and_harvest_nft_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 1, &rust_biguint!(5), &0u8);
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 2, &rust_biguint!(1), &0u8);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(3),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_user_error(ERR_TOKEN_NOT_WHITELISTED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_user_error(ERR_TOKEN_NOT_NFT);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(3),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            2,
+            &rust_biguint!(1),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_AIRDROP);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_nft_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id!(NFT_TOKEN_ID),
+                    1,
+                    managed_biguint!(4),
+                );
+            },
+        )
+        .assert_user_error(ERR_MORE_THAN_CLAIM);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_nft_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id!(NFT_TOKEN_ID),
+                    1,
+                    managed_biguint!(1),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_nft_balance(
+        owner_address,
+        NFT_TOKEN_ID,
+        1,
+        &rust_biguint!(3),
+        Option::<&u8>::None,
+    );
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_nft_claims(&managed_address!(user_addr))
+                    .into_iter()
+                    .count(),
+                2
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_nft_balance(
+        user_addr,
+        NFT_TOKEN_ID,
+        1,
+        &rust_biguint!(2),
+        Option::<&u8>::None,
+    );
+    b_wrapper.check_nft_balance(
+        user_addr,
+        NFT_TOKEN_ID,
+        2,
+        &rust_biguint!(0),
+        Option::<&u8>::None,
+    );
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_nft_balance(
+        user_addr,
+        NFT_TOKEN_ID,
+        2,
+        &rust_biguint!(1),
+        Option::<&u8>::None,
+    );
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_nft_claims(&managed_address!(user_addr))
+                    .into_iter()
+                    .count(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether harvested claims are sent to the beneficiaries set by the claim owner and to the receiver given to claimTo
+fn harvest_claim_to_beneficiary_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+    let beneficiary_addr = &setup.third_user_address;
+    let receiver_addr = &setup.first_user_address;
+
+    for (claim_type, amount) in [
+        (CLAIM_TYPE_REWARD, 1_000u64),
+        (CLAIM_TYPE_AIRDROP, 2_000u64),
+        (CLAIM_TYPE_ALLOCATION, 500u64),
+    ] {
+        b_wrapper
+            .execute_esdt_transfer(
+                owner_address,
+                &setup.contract_wrapper,
+                TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.add_claim(
+                        &managed_address!(user_addr),
+                        claim_type,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_beneficiary(managed_address!(beneficiary_addr), OptionalValue::None);
+                sc.set_beneficiary(
+                    managed_address!(user_addr),
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_claim_receiver(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP),
+                managed_address!(beneficiary_addr)
+            );
+            assert_eq!(
+                sc.view_claim_receiver(&managed_address!(user_addr), &CLAIM_TYPE_REWARD),
+                managed_address!(user_addr)
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_to(
+                    managed_address!(receiver_addr),
+                    OptionalValue::Some(CLAIM_TYPE_ALLOCATION),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(receiver_addr, TOKEN_ID, &rust_biguint!(1_500));
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+    b_wrapper.check_esdt_balance(beneficiary_addr, TOKEN_ID, &rust_biguint!(3_000));
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_beneficiary(OptionalValue::None);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_claim_receiver(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP),
+                managed_address!(user_addr)
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether a relayer can harvest the claims of an address with its signed authorization, the tokens being sent to the address
+        //Tests whether the signed fee is deducted from the harvested tokens and sent to the relayer
+        //Tests whether a fee higher than the harvested amount is rejected
+        //Tests whether a signed authorization cannot be replayed once its nonce is used
+fn harvest_claim_for_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let relayer_addr = &setup.third_user_address;
+    let keypair = signing_keypair(9u8);
+    // The address of an account is its ed25519 public key
+    let user_addr = &Address::from(keypair.public.to_bytes());
+    b_wrapper.create_user_account_fixed_address(user_addr, &rust_biguint!(0));
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    let mut messages = Vec::new();
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            for fee_amount in [150u64, 10u64] {
+                let relayed_claim = RelayedClaim::<DebugApi> {
+                    address: managed_address!(user_addr),
+                    nonce: 0,
+                    deadline: 200,
+                    fee_token: managed_token_id_wrapped!(TOKEN_ID),
+                    fee_amount: managed_biguint!(fee_amount),
+                };
+                let mut buffer = sc.blockchain().get_sc_address().as_managed_buffer().clone();
+                buffer.append(&sc.serializer().top_encode_to_managed_buffer(&relayed_claim));
+                messages.push(buffer.to_boxed_bytes().into_vec());
+            }
+        })
+        .assert_ok();
+    let high_fee_signature = keypair.sign(&messages[0]).to_bytes();
+    let signature = keypair.sign(&messages[1]).to_bytes();
+
+    b_wrapper
+        .execute_tx(
+            relayer_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_for(
+                    managed_address!(user_addr),
+                    200,
+                    ManagedByteArray::new_from_bytes(&high_fee_signature),
+                    OptionalValue::Some(MultiValue2(
+                        (managed_token_id_wrapped!(TOKEN_ID), managed_biguint!(150)).into(),
+                    )),
+                );
+            },
+        )
+        .assert_user_error(ERR_RELAYER_FEE_TOO_HIGH);
+
+    b_wrapper
+        .execute_tx(
+            relayer_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_for(
+                    managed_address!(user_addr),
+                    200,
+                    ManagedByteArray::new_from_bytes(&signature),
+                    OptionalValue::Some(MultiValue2(
+                        (managed_token_id_wrapped!(TOKEN_ID), managed_biguint!(10)).into(),
+                    )),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(90));
+    b_wrapper.check_esdt_balance(relayer_addr, TOKEN_ID, &rust_biguint!(1_010));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.relayed_claim_nonce(&managed_address!(user_addr)).get(),
+                1
+            );
+            assert_eq!(
+                sc.view_claims(
+                    &managed_address!(user_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                ),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            relayer_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_for(
+                    managed_address!(user_addr),
+                    200,
+                    ManagedByteArray::new_from_bytes(&signature),
+                    OptionalValue::Some(MultiValue2(
+                        (managed_token_id_wrapped!(TOKEN_ID), managed_biguint!(10)).into(),
+                    )),
+                );
+            },
+        )
+        .assert_user_error(ERR_INVALID_SIGNATURE);
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(90));
+    b_wrapper.check_esdt_balance(relayer_addr, TOKEN_ID, &rust_biguint!(1_010));
+}
+
+#[test] //Tests whether relayed claims are rejected once their authorization has expired
+fn harvest_claim_for_expired_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+    let relayer_addr = &setup.third_user_address;
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_tx(
+            relayer_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_for(
+                    managed_address!(user_addr),
+                    50,
+                    ManagedByteArray::new_from_bytes(&[0u8; 64]),
+                    OptionalValue::Some(MultiValue2(
+                        (managed_token_id_wrapped!(TOKEN_ID), managed_biguint!(10)).into(),
+                    )),
+                );
+            },
+        )
+        .assert_user_error(ERR_RELAYED_CLAIM_EXPIRED);
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.relayed_claim_nonce(&managed_address!(user_addr)).get(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether vested claims can only be harvested as they unlock linearly after the cliff
+        //Tests whether a second vesting schedule cannot be added while the first one is still active
+        //Tests whether invalid vesting schedules are rejected
+fn harvest_vested_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000_000),
+            |sc| {
+                sc.add_vested_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ALLOCATION,
+                    1_100,
+                    100,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_INVALID_VESTING_SCHEDULE);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000_000),
+            |sc| {
+                sc.add_vested_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ALLOCATION,
+                    100,
+                    1_100,
+                    OptionalValue::Some(300),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(200);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_ALLOCATION),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NON_ZERO_VALUE);
+
+    b_wrapper.set_block_timestamp(600);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_ALLOCATION),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(500_000));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                500_000
+            );
+            assert_eq!(
+                sc.view_locked_claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                ),
+                500_000
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000_000),
+            |sc| {
+                sc.add_vested_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ALLOCATION,
+                    600,
+                    1_600,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_VESTING_SCHEDULE_ACTIVE);
+
+    b_wrapper.set_block_timestamp(1_100);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000_000));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                0
+            );
+            assert!(sc
+                .vesting_schedule(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .is_empty());
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether removing a vested claim entirely clears its vesting schedule, so that a claim added later is not locked by it
+fn remove_vested_claim_then_add_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_vested_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ALLOCATION,
+                    100,
+                    1_100,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(200);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ALLOCATION,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_ALLOCATION,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc
+                .vesting_schedule(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .is_empty());
+            assert_eq!(
+                sc.view_locked_claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                ),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_ALLOCATION),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(500));
+}
+
+#[test] //Tests whether expired claims cannot be harvested and are skipped when harvesting all claim types
+        //Tests whether only the depositor or the owner can return the tokens of an expired claim to the depositor
+fn expire_and_reclaim_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let depositor_addr = &setup.first_user_address;
+    let user_addr = &setup.third_user_address;
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::Some(50),
+                );
+            },
+        )
+        .assert_user_error(ERR_INVALID_EXPIRY);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::Some(200),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            depositor_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue2(
+                    (managed_address!(user_addr), CLAIM_TYPE_AIRDROP).into(),
+                ));
+                sc.reclaim_expired_claims(args);
+            },
+        )
+        .assert_user_error(ERR_CLAIM_NOT_EXPIRED);
+
+    b_wrapper.set_block_timestamp(200);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_AIRDROP),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_CLAIM_EXPIRED);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_500));
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue2(
+                    (managed_address!(user_addr), CLAIM_TYPE_AIRDROP).into(),
+                ));
+                sc.reclaim_expired_claims(args);
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
+
+    b_wrapper
+        .execute_tx(
+            depositor_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue2(
+                    (managed_address!(user_addr), CLAIM_TYPE_AIRDROP).into(),
+                ));
+                sc.reclaim_expired_claims(args);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(1_000));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_AIRDROP,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                0
+            );
+            assert!(sc
+                .claim_expiry(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP)
+                .is_empty());
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether a depositor can only set the expiry of a claim funded by itself alone and cannot shorten it
+        //Tests whether other depositors cannot add to a claim while it has an expiry set by another depositor
+fn claim_expiry_only_applies_to_own_deposits_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let depositor_addr = &setup.first_user_address;
+    let other_depositor_addr = &setup.second_user_address;
+    let user_addr = &setup.third_user_address;
+
+    b_wrapper.set_esdt_balance(other_depositor_addr, TOKEN_ID, &rust_biguint!(1_000));
+    b_wrapper.set_block_timestamp(100);
+
+    let add_claim = |b_wrapper: &mut BlockchainStateWrapper,
+                     caller: &Address,
+                     claim_type: u8,
+                     amount: u64,
+                     expiry: Option<u64>| {
+        b_wrapper.execute_esdt_transfer(
+            caller,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    claim_type,
+                    OptionalValue::from(expiry),
+                );
+            },
+        )
+    };
+
+    add_claim(b_wrapper, owner_address, CLAIM_TYPE_REWARD, 1_000, None).assert_ok();
+    add_claim(b_wrapper, depositor_addr, CLAIM_TYPE_REWARD, 1, Some(150))
+        .assert_user_error(ERR_CLAIM_FUNDED_BY_OTHER_DEPOSITORS);
+
+    add_claim(
+        b_wrapper,
+        depositor_addr,
+        CLAIM_TYPE_AIRDROP,
+        500,
+        Some(300),
+    )
+    .assert_ok();
+    add_claim(
+        b_wrapper,
+        depositor_addr,
+        CLAIM_TYPE_AIRDROP,
+        100,
+        Some(200),
+    )
+    .assert_ok();
+    add_claim(
+        b_wrapper,
+        other_depositor_addr,
+        CLAIM_TYPE_AIRDROP,
+        100,
+        None,
+    )
+    .assert_user_error(ERR_CLAIM_EXPIRY_SET_BY_OTHER_DEPOSITOR);
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim_expiry(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP)
+                    .get()
+                    .timestamp,
+                300
+            );
+        })
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(300);
+
+    b_wrapper
+        .execute_tx(
+            depositor_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue2(
+                    (managed_address!(user_addr), CLAIM_TYPE_AIRDROP).into(),
+                ));
+                sc.reclaim_expired_claims(args);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(1_000));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                1_000
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether an expiry cannot be set beyond the maximum expiry period and is cleared once the claims of its claim type are emptied
+        //Tests whether expired claims are reclaimed in their tokens even once these are no longer whitelisted
+fn claim_expiry_cleared_and_reclaimed_in_delisted_tokens_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let depositor_addr = &setup.first_user_address;
+    let user_addr = &setup.third_user_address;
+
+    b_wrapper.set_esdt_balance(depositor_addr, WRONG_TOKEN_ID, &rust_biguint!(300));
+    b_wrapper.set_block_timestamp(100);
+
+    let add_claim = |b_wrapper: &mut BlockchainStateWrapper,
+                     caller: &Address,
+                     token: &[u8],
+                     claim_type: u8,
+                     amount: u64,
+                     expiry: Option<u64>| {
+        b_wrapper.execute_esdt_transfer(
+            caller,
+            &setup.contract_wrapper,
+            token,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    claim_type,
+                    OptionalValue::from(expiry),
+                );
+            },
+        )
+    };
+
+    add_claim(
+        b_wrapper,
+        depositor_addr,
+        TOKEN_ID,
+        CLAIM_TYPE_AIRDROP,
+        1,
+        Some(101 + MAX_CLAIM_EXPIRY_PERIOD),
+    )
+    .assert_user_error(ERR_EXPIRY_TOO_FAR);
+    add_claim(
+        b_wrapper,
+        depositor_addr,
+        TOKEN_ID,
+        CLAIM_TYPE_AIRDROP,
+        1,
+        Some(100 + MAX_CLAIM_EXPIRY_PERIOD),
+    )
+    .assert_ok();
+    add_claim(
+        b_wrapper,
+        owner_address,
+        TOKEN_ID,
+        CLAIM_TYPE_AIRDROP,
+        500,
+        None,
+    )
+    .assert_user_error(ERR_CLAIM_EXPIRY_SET_BY_OTHER_DEPOSITOR);
+
+    // Harvesting the whole claim clears its expiry, so that other depositors can add to it again
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_AIRDROP),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc
+                .claim_expiry(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP)
+                .is_empty());
+        })
+        .assert_ok();
+
+    add_claim(
+        b_wrapper,
+        owner_address,
+        TOKEN_ID,
+        CLAIM_TYPE_AIRDROP,
+        500,
+        None,
+    )
+    .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(WRONG_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    add_claim(
+        b_wrapper,
+        depositor_addr,
+        WRONG_TOKEN_ID,
+        CLAIM_TYPE_ROYALTY,
+        300,
+        Some(300),
+    )
+    .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim_token(managed_token_id_wrapped!(WRONG_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(300);
+
+    b_wrapper
+        .execute_tx(
+            depositor_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue2(
+                    (managed_address!(user_addr), CLAIM_TYPE_ROYALTY).into(),
+                ));
+                sc.reclaim_expired_claims(args);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(depositor_addr, WRONG_TOKEN_ID, &rust_biguint!(300));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ROYALTY,
+                    &managed_token_id_wrapped!(WRONG_TOKEN_ID)
+                ),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.total_reserved(&managed_token_id_wrapped!(WRONG_TOKEN_ID))
+                    .get(),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether addresses included in a distribution can harvest their leaf once by providing a valid proof
+        //Tests whether an address cannot harvest the leaf of another address
+fn claim_distribution_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let second_user_addr = &setup.second_user_address;
+    let third_user_addr = &setup.third_user_address;
+
+    let mut leaves = [[0u8; 32]; 2];
+    let mut root = [0u8; 32];
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let recipients = [(first_user_addr, 1_000u64), (second_user_addr, 2_000u64)];
+            for (index, (address, amount)) in recipients.iter().enumerate() {
+                let mut leaf = ManagedBuffer::new_from_bytes(&(index as u64).to_be_bytes());
+                leaf.append(managed_address!(*address).as_managed_buffer());
+                leaf.append(&managed_biguint!(*amount).to_bytes_be_buffer());
+                leaves[index] = sc.crypto().keccak256(&leaf).to_byte_array();
+            }
+
+            let mut pair = ManagedBuffer::new();
+            pair.append_bytes(&leaves[0].min(leaves[1]));
+            pair.append_bytes(&leaves[0].max(leaves[1]));
+            root = sc.crypto().keccak256(&pair).to_byte_array();
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(3_000),
+            |sc| {
+                let distribution_id = sc.create_distribution(
+                    CLAIM_TYPE_REWARD,
+                    ManagedByteArray::new_from_bytes(&root),
+                    OptionalValue::None,
+                );
+                assert_eq!(distribution_id, 1);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            third_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut proof = MultiValueEncoded::new();
+                proof.push(ManagedByteArray::new_from_bytes(&leaves[1]));
+                sc.claim_distribution(1, 0, managed_biguint!(1_000), proof);
+            },
+        )
+        .assert_user_error(ERR_INVALID_PROOF);
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut proof = MultiValueEncoded::new();
+                proof.push(ManagedByteArray::new_from_bytes(&leaves[1]));
+                sc.claim_distribution(1, 0, managed_biguint!(1_000), proof);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut proof = MultiValueEncoded::new();
+                proof.push(ManagedByteArray::new_from_bytes(&leaves[1]));
+                sc.claim_distribution(1, 0, managed_biguint!(1_000), proof);
+            },
+        )
+        .assert_user_error(ERR_DISTRIBUTION_LEAF_CLAIMED);
+
+    b_wrapper
+        .execute_tx(
+            second_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut proof = MultiValueEncoded::new();
+                proof.push(ManagedByteArray::new_from_bytes(&leaves[0]));
+                sc.claim_distribution(1, 1, managed_biguint!(2_000), proof);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(first_user_addr, TOKEN_ID, &rust_biguint!(2_000));
+    b_wrapper.check_esdt_balance(second_user_addr, TOKEN_ID, &rust_biguint!(2_000));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.is_distribution_leaf_claimed(1, 0));
+            assert!(sc.is_distribution_leaf_claimed(1, 1));
+            assert_eq!(sc.distribution(1).get().claimed_amount, 3_000);
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether a distribution cannot be harvested once its deadline has passed
+        //Tests whether only the admin or the depositor can close a distribution, and only after its deadline
+        //Tests whether closing a distribution returns its unclaimed tokens to the depositor
+fn close_distribution_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let third_user_addr = &setup.third_user_address;
+
+    b_wrapper.set_block_timestamp(100);
+
+    let mut root = [0u8; 32];
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let mut leaf = ManagedBuffer::new_from_bytes(&0u64.to_be_bytes());
+            leaf.append(managed_address!(first_user_addr).as_managed_buffer());
+            leaf.append(&managed_biguint!(1_000).to_bytes_be_buffer());
+            root = sc.crypto().keccak256(&leaf).to_byte_array();
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(3_000),
+            |sc| {
+                sc.create_distribution(
+                    CLAIM_TYPE_REWARD,
+                    ManagedByteArray::new_from_bytes(&root),
+                    OptionalValue::Some(200),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.close_distribution(1);
+            },
+        )
+        .assert_user_error(ERR_DISTRIBUTION_NOT_ENDED);
+
+    b_wrapper.set_block_timestamp(200);
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_distribution(1, 0, managed_biguint!(1_000), MultiValueEncoded::new());
+            },
+        )
+        .assert_user_error(ERR_DISTRIBUTION_ENDED);
+
+    b_wrapper.set_block_timestamp(199);
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_distribution(1, 0, managed_biguint!(1_000), MultiValueEncoded::new());
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(200);
+
+    b_wrapper
+        .execute_tx(
+            third_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.close_distribution(1);
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.close_distribution(1);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.close_distribution(1);
+            },
+        )
+        .assert_user_error(ERR_DISTRIBUTION_EXHAUSTED);
+
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_999_000));
+    b_wrapper.check_esdt_balance(first_user_addr, TOKEN_ID, &rust_biguint!(2_000));
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.distribution(1).get().amount, 1_000);
+            assert_eq!(
+                sc.total_reserved(&managed_token_id_wrapped!(TOKEN_ID))
+                    .get(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether only privileged addresses can set the voucher signer and only depositors can fund the voucher pool
+        //Tests whether vouchers cannot be redeemed without a signer or after their deadline
+        //Tests whether the owner can withdraw from the voucher pool
+fn voucher_pool_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.third_user_address;
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.redeem_voucher(
+                    CLAIM_TYPE_ROYALTY,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(100),
+                    1,
+                    200,
+                    ManagedByteArray::new_from_bytes(&[0u8; 64]),
+                );
+            },
+        )
+        .assert_user_error(ERR_VOUCHER_SIGNER_NOT_SET);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_voucher_signer(ManagedByteArray::new_from_bytes(&[1u8; 32]));
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_voucher_signer(ManagedByteArray::new_from_bytes(&[1u8; 32]));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            user_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.fund_voucher_pool();
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_AUTHORIZED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.fund_voucher_pool();
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.redeem_voucher(
+                    CLAIM_TYPE_ROYALTY,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(100),
+                    1,
+                    50,
+                    ManagedByteArray::new_from_bytes(&[0u8; 64]),
+                );
+            },
+        )
+        .assert_user_error(ERR_VOUCHER_EXPIRED);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_voucher_pool(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(1_001),
+                );
+            },
+        )
+        .assert_user_error(ERR_VOUCHER_POOL_INSUFFICIENT);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_voucher_pool(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(400),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.voucher_pool(&managed_token_id_wrapped!(TOKEN_ID)).get(),
+                600
+            );
+        })
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_999_400));
+}
+
+#[test] //Tests whether a voucher signed by the voucher signer can be redeemed by the address it was issued for
+        //Tests whether a redeemed voucher cannot be redeemed again with the same nonce
+        //Tests whether vouchers of unregistered claim types are rejected
+fn redeem_signed_voucher_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.third_user_address;
+    let keypair = signing_keypair(7u8);
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_voucher_signer(ManagedByteArray::new_from_bytes(&keypair.public.to_bytes()));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.fund_voucher_pool();
+            },
+        )
+        .assert_ok();
+
+    let mut message = Vec::new();
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let voucher = Voucher::<DebugApi> {
+                address: managed_address!(user_addr),
+                claim_type: CLAIM_TYPE_ROYALTY,
+                token: managed_token_id_wrapped!(TOKEN_ID),
+                amount: managed_biguint!(300),
+                nonce: 1,
+                deadline: 200,
+            };
+            let mut buffer = sc.blockchain().get_sc_address().as_managed_buffer().clone();
+            buffer.append(&sc.serializer().top_encode_to_managed_buffer(&voucher));
+            message = buffer.to_boxed_bytes().into_vec();
+        })
+        .assert_ok();
+    let signature = keypair.sign(&message).to_bytes();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.redeem_voucher(
+                    5u8,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(300),
+                    1,
+                    200,
+                    ManagedByteArray::new_from_bytes(&signature),
+                );
+            },
+        )
+        .assert_user_error(ERR_CLAIM_TYPE_NOT_REGISTERED);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.redeem_voucher(
+                    CLAIM_TYPE_ROYALTY,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(300),
+                    1,
+                    200,
+                    ManagedByteArray::new_from_bytes(&signature),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_300));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.voucher_pool(&managed_token_id_wrapped!(TOKEN_ID)).get(),
+                managed_biguint!(700)
+            );
+            assert_eq!(
+                sc.voucher_nonce_used(&managed_address!(user_addr), 1).get(),
+                true
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.redeem_voucher(
+                    CLAIM_TYPE_ROYALTY,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(300),
+                    1,
+                    200,
+                    ManagedByteArray::new_from_bytes(&signature),
+                );
+            },
+        )
+        .assert_user_error(ERR_VOUCHER_NONCE_USED);
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_300));
+}
+
+#[test] //Tests whether the reserved totals follow the claims and whether only the surplus above them can be withdrawn
+        //Tests whether the reserved totals cannot be decreased below zero
+fn solvency_and_withdraw_surplus_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    for (claim_type, amount) in [(CLAIM_TYPE_REWARD, 1_000u64), (CLAIM_TYPE_ROYALTY, 500u64)] {
+        b_wrapper
+            .execute_esdt_transfer(
+                owner_address,
+                &setup.contract_wrapper,
+                TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.add_claim(
+                        &managed_address!(user_addr),
+                        claim_type,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_surplus(managed_token_id_wrapped!(TOKEN_ID));
+            },
+        )
+        .assert_user_error(ERR_NO_SURPLUS);
+
+    // Tokens sent to the smart contract without a claim
+    b_wrapper.set_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        TOKEN_ID,
+        &rust_biguint!(1_800),
+    );
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let solvency = sc.view_solvency();
+            assert_eq!(solvency.len(), 1);
+            assert_eq!(solvency.get(0).reserved, 1_500);
+            assert_eq!(solvency.get(0).balance, 1_800);
+            assert!(solvency.get(0).solvent);
+            assert_eq!(
+                sc.total_reserved_by_type(
+                    &CLAIM_TYPE_ROYALTY,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                500
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.total_reserved(&managed_token_id_wrapped!(TOKEN_ID))
+                    .get(),
+                500
+            );
+            assert_eq!(
+                sc.total_reserved_by_type(&CLAIM_TYPE_REWARD, &managed_token_id_wrapped!(TOKEN_ID))
+                    .get(),
+                0
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_surplus(managed_token_id_wrapped!(TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_998_800));
+    b_wrapper.check_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        TOKEN_ID,
+        &rust_biguint!(500),
+    );
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.decrease_reserved(
+                    Some(&CLAIM_TYPE_ROYALTY),
+                    &managed_token_id_wrapped!(TOKEN_ID),
+                    &managed_biguint!(501),
+                );
+            },
+        )
+        .assert_user_error(ERR_RESERVED_TOTAL_EXCEEDED);
+}
+
+#[test] //Tests whether the owner can only rescue the surplus above the reserved amount of a token, whether or not it is whitelisted
+        //Tests whether the NFTs and SFTs reserved by claims cannot be rescued, whether or not their collection is whitelisted
+        //Tests whether the EGLD reserved by claims cannot be rescued by giving a nonce
+fn rescue_tokens_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    // Tokens sent to the smart contract without a claim
+    b_wrapper.set_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        TOKEN_ID,
+        &rust_biguint!(1_200),
+    );
+    b_wrapper.set_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        WRONG_TOKEN_ID,
+        &rust_biguint!(300),
+    );
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.rescue_tokens(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    0,
+                    managed_biguint!(300),
+                );
+            },
+        )
+        .assert_user_error(ERR_RESCUE_MORE_THAN_SURPLUS);
+
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 1, &rust_biguint!(5), &0u8);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(3),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_ok();
+
+    // SFTs sent to the smart contract without a claim
+    b_wrapper.set_nft_balance(
+        setup.contract_wrapper.address_ref(),
+        NFT_TOKEN_ID,
+        1,
+        &rust_biguint!(5),
+        &0u8,
+    );
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim_token(managed_token_id_wrapped!(TOKEN_ID));
+                sc.remove_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.rescue_tokens(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    0,
+                    managed_biguint!(300),
+                );
+            },
+        )
+        .assert_user_error(ERR_RESCUE_MORE_THAN_SURPLUS);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.rescue_tokens(
+                    managed_token_id_wrapped!(NFT_TOKEN_ID),
+                    1,
+                    managed_biguint!(3),
+                );
+            },
+        )
+        .assert_user_error(ERR_RESCUE_RESERVED_NFT);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.rescue_tokens(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    0,
+                    managed_biguint!(200),
+                );
+                sc.rescue_tokens(
+                    managed_token_id_wrapped!(WRONG_TOKEN_ID),
+                    0,
+                    managed_biguint!(300),
+                );
+                sc.rescue_tokens(
+                    managed_token_id_wrapped!(NFT_TOKEN_ID),
+                    1,
+                    managed_biguint!(2),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_nft_balance(
+        owner_address,
+        NFT_TOKEN_ID,
+        1,
+        &rust_biguint!(4),
+        Option::<&u8>::None,
+    );
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_999_200));
+    b_wrapper.check_esdt_balance(owner_address, WRONG_TOKEN_ID, &rust_biguint!(1_000_300));
+    b_wrapper.check_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_egld_token_id!());
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    for (nonce, error) in [
+        (1, ERR_EGLD_NONCE_NOT_ZERO),
+        (0, ERR_RESCUE_MORE_THAN_SURPLUS),
+    ] {
+        b_wrapper
+            .execute_tx(
+                &owner_address,
+                &setup.contract_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.rescue_tokens(managed_egld_token_id!(), nonce, managed_biguint!(1_000));
+                },
+            )
+            .assert_user_error(error);
+    }
+
+    b_wrapper.check_egld_balance(setup.contract_wrapper.address_ref(), &rust_biguint!(1_000));
+}
+
+#[test] //Tests whether the admin role is only transferred once the proposed address accepts it
+fn propose_and_accept_admin_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_address = &setup.first_user_address;
+    let new_admin_address = &setup.third_user_address;
+
+    b_wrapper
+        .execute_tx(
+            first_user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose_admin(managed_address!(first_user_address));
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_ADMIN);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose_admin(managed_address!(new_admin_address));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            first_user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.accept_admin();
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_PENDING_ADMIN);
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.admin().get(), managed_address!(owner_address));
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            new_admin_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.accept_admin();
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_type(4u8, managed_buffer!(b"Bounty"));
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_ADMIN);
+
+    b_wrapper
+        .execute_tx(
+            new_admin_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_type(4u8, managed_buffer!(b"Bounty"));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.admin().get(), managed_address!(new_admin_address));
+            assert!(sc.pending_admin().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether dangerous admin operations are queued while a timelock delay is set, can only be executed after the delay and can be cancelled by privileged addresses
+fn timelock_remove_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_address = &setup.first_user_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+                sc.set_timelock_delay(100);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(400),
+                );
+                sc.remove_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(100),
+                );
+                sc.set_timelock_delay(0);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                1_000
+            );
+            assert_eq!(sc.queued_operations().len(), 3);
+            assert_eq!(sc.timelock_delay().get(), 100);
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(1);
+            },
+        )
+        .assert_user_error(ERR_OPERATION_NOT_READY);
+
+    b_wrapper
+        .execute_tx(
+            first_user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.cancel_operation(2);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_tx(
+            first_user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(1);
+            },
+        )
+        .assert_user_error(ERR_ADDRESS_NOT_ADMIN);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(2);
+            },
+        )
+        .assert_user_error(ERR_OPERATION_NOT_FOUND);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(1);
+                sc.execute_operation(3);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                600
+            );
+            assert_eq!(sc.queued_operations().len(), 0);
+            assert_eq!(sc.timelock_delay().get(), 0);
+        })
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_999_400));
+}
+
+#[test] //Tests whether NFT claim removals and voucher pool withdrawals are queued while a timelock delay is set and only applied once executed
+fn timelock_remove_nft_claim_and_withdraw_voucher_pool_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.second_user_address;
+
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 1, &rust_biguint!(5), &0u8);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(3),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.fund_voucher_pool();
+                sc.set_timelock_delay(100);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_nft_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id!(NFT_TOKEN_ID),
+                    1,
+                    managed_biguint!(2),
+                );
+                sc.withdraw_voucher_pool(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(400),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let nft_nonce = NftNonce {
+                token: managed_token_id!(NFT_TOKEN_ID),
+                nonce: 1,
+            };
+            assert_eq!(
+                sc.nft_claims(&managed_address!(user_addr), &CLAIM_TYPE_REWARD)
+                    .get(&nft_nonce),
+                Some(managed_biguint!(3))
+            );
+            assert_eq!(
+                sc.voucher_pool(&managed_token_id_wrapped!(TOKEN_ID)).get(),
+                1_000
+            );
+            assert_eq!(sc.queued_operations().len(), 2);
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(1);
+            },
+        )
+        .assert_user_error(ERR_OPERATION_NOT_READY);
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(1);
+                sc.execute_operation(2);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let nft_nonce = NftNonce {
+                token: managed_token_id!(NFT_TOKEN_ID),
+                nonce: 1,
+            };
+            assert_eq!(
+                sc.nft_claims(&managed_address!(user_addr), &CLAIM_TYPE_REWARD)
+                    .get(&nft_nonce),
+                Some(managed_biguint!(1))
+            );
+            assert_eq!(
+                sc.voucher_pool(&managed_token_id_wrapped!(TOKEN_ID)).get(),
+                600
+            );
+            assert_eq!(sc.queued_operations().len(), 0);
+        })
+        .assert_ok();
+
+    b_wrapper.check_nft_balance(
+        owner_address,
+        NFT_TOKEN_ID,
+        1,
+        &rust_biguint!(4),
+        Option::<&u8>::None,
+    );
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_999_400));
+}
+
+#[test] //Tests whether voucher signer, staking contract and execute target changes are queued while a timelock delay is set and only applied once executed
+fn timelock_set_voucher_signer_staking_contract_and_execute_target_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let pauser_addr = &setup.first_user_address;
+
+    let target_wrapper = b_wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(owner_address),
+        claims::contract_obj,
+        WASM_PATH,
+    );
+    let signer = signing_keypair(1).public.to_bytes();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_timelock_delay(100);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            pauser_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_voucher_signer(ManagedByteArray::new_from_bytes(&signer));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_address!(target_wrapper.address_ref()),
+                    managed_buffer!(b"stake"),
+                );
+                sc.add_execute_target(
+                    managed_address!(target_wrapper.address_ref()),
+                    managed_buffer!(b"addClaim"),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.voucher_signer().is_empty());
+            assert!(sc
+                .staking_contract(&managed_token_id_wrapped!(TOKEN_ID))
+                .is_empty());
+            assert_eq!(sc.execute_targets().len(), 0);
+            assert_eq!(sc.queued_operations().len(), 3);
+        })
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(100);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_operation(1);
+                sc.execute_operation(2);
+                sc.execute_operation(3);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.voucher_signer().get().to_byte_array(), signer);
+            assert_eq!(
+                sc.staking_contract(&managed_token_id_wrapped!(TOKEN_ID))
+                    .get()
+                    .address,
+                managed_address!(target_wrapper.address_ref())
+            );
+            assert!(sc.execute_targets().contains(&ExecuteTarget {
+                address: managed_address!(target_wrapper.address_ref()),
+                function: managed_buffer!(b"addClaim"),
+            }));
+            assert_eq!(sc.queued_operations().len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether a depositor can only add claims of its claim types and within its quota, which is reset every epoch
+        //Tests whether the quota of a depositor also applies to the distributions it creates and the voucher pools it funds
+fn depositor_claim_types_and_quota_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let depositor_addr = &setup.second_user_address;
+
+    b_wrapper.set_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(5_000));
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut claim_types = MultiValueEncoded::new();
+                claim_types.push(CLAIM_TYPE_ROYALTY);
+                sc.set_depositor_claim_types(managed_address!(depositor_addr), claim_types);
+                sc.set_depositor_quota(
+                    managed_address!(depositor_addr),
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(1_000),
+                    true,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(first_user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_DEPOSITOR_CLAIM_TYPE_NOT_ALLOWED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(first_user_addr),
+                    CLAIM_TYPE_ROYALTY,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue3(
+                    (
+                        managed_address!(first_user_addr),
+                        CLAIM_TYPE_ROYALTY,
+                        managed_biguint!(500),
+                    )
+                        .into(),
+                ));
+                sc.add_claims(args);
+            },
+        )
+        .assert_user_error(ERR_DEPOSITOR_QUOTA_EXCEEDED);
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_remaining_depositor_quota(
+                    &managed_address!(depositor_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .into_option()
+                .unwrap(),
+                400
+            );
+        })
+        .assert_ok();
+
+    b_wrapper.set_block_epoch(1);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue3(
+                    (
+                        managed_address!(first_user_addr),
+                        CLAIM_TYPE_ROYALTY,
+                        managed_biguint!(500),
+                    )
+                        .into(),
+                ));
+                sc.add_claims(args);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_remaining_depositor_quota(
+                    &managed_address!(depositor_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .into_option()
+                .unwrap(),
+                500
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.create_distribution(
+                    CLAIM_TYPE_ROYALTY,
+                    ManagedByteArray::new_from_bytes(&[0u8; 32]),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_DEPOSITOR_QUOTA_EXCEEDED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.fund_voucher_pool();
+            },
+        )
+        .assert_user_error(ERR_DEPOSITOR_QUOTA_EXCEEDED);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(300),
+            |sc| {
+                sc.create_distribution(
+                    CLAIM_TYPE_ROYALTY,
+                    ManagedByteArray::new_from_bytes(&[0u8; 32]),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(200),
+            |sc| {
+                sc.fund_voucher_pool();
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.view_remaining_depositor_quota(
+                    &managed_address!(depositor_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .into_option()
+                .unwrap(),
+                0
+            );
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_depositor_quota(
+                    managed_address!(depositor_addr),
+                    managed_token_id_wrapped!(TOKEN_ID),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc
+                .view_remaining_depositor_quota(
+                    &managed_address!(depositor_addr),
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .into_option()
+                .is_none());
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether removing a claim refunds its depositors proportionally to the part of the claim each one funded
+fn remove_claim_refunds_funding_depositors_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let depositor_addr = &setup.second_user_address;
+
+    b_wrapper.set_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(1_000));
+
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(300),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(first_user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            depositor_addr,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(first_user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim(
+                    &managed_address!(first_user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(200),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(owner_address, TOKEN_ID, &rust_biguint!(4_999_850));
+    b_wrapper.check_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(950));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let claim_funding = sc.claim_funding(
+                &managed_address!(first_user_addr),
+                &CLAIM_TYPE_REWARD,
+                &managed_token_id_wrapped!(TOKEN_ID),
+            );
+            assert_eq!(
+                claim_funding.get(&managed_address!(owner_address)).unwrap(),
+                150
+            );
+            assert_eq!(
+                claim_funding
+                    .get(&managed_address!(depositor_addr))
+                    .unwrap(),
+                50
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether removing an NFT or SFT claim refunds the depositors that added it proportionally to the quantity each one added
+fn remove_nft_claim_refunds_depositors_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.third_user_address;
+    let depositor_addr = &setup.second_user_address;
+
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 1, &rust_biguint!(2), &0u8);
+    b_wrapper.set_nft_balance(depositor_addr, NFT_TOKEN_ID, 1, &rust_biguint!(2), &0u8);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    for depositor in [owner_address, depositor_addr] {
+        b_wrapper
+            .execute_esdt_transfer(
+                depositor,
+                &setup.contract_wrapper,
+                NFT_TOKEN_ID,
+                1,
+                &rust_biguint!(2),
+                |sc| {
+                    sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+                },
+            )
+            .assert_ok();
+    }
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_nft_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id!(NFT_TOKEN_ID),
+                    1,
+                    managed_biguint!(2),
+                );
+            },
+        )
+        .assert_ok();
+
+    for depositor in [owner_address, depositor_addr] {
+        b_wrapper.check_nft_balance(
+            depositor,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(1),
+            Option::<&u8>::None,
+        );
+    }
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let nft_nonce = NftNonce {
+                token: managed_token_id!(NFT_TOKEN_ID),
+                nonce: 1,
+            };
+            let nft_claim_funding =
+                sc.nft_claim_funding(&managed_address!(user_addr), &CLAIM_TYPE_REWARD, &nft_nonce);
+            assert_eq!(
+                nft_claim_funding.get(&managed_address!(owner_address)),
+                Some(managed_biguint!(1))
+            );
+            assert_eq!(
+                nft_claim_funding.get(&managed_address!(depositor_addr)),
+                Some(managed_biguint!(1))
+            );
+            assert_eq!(
+                sc.nft_reserved(&managed_token_id!(NFT_TOKEN_ID), 1).get(),
+                managed_biguint!(2)
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether a depositor can claw back its deposit within the grace period, but not once it is over or once the claim is harvested
+        //Tests whether a later deposit does not extend the grace period of the previous ones
+fn clawback_claim_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let depositor_addr = &setup.second_user_address;
+
+    b_wrapper.set_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(1_000));
+    b_wrapper.set_block_timestamp(1_000);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_clawback_grace_period(100);
+            },
+        )
+        .assert_ok();
+
+    let add_claim = |b_wrapper: &mut BlockchainStateWrapper, amount: u64| {
+        b_wrapper
+            .execute_esdt_transfer(
+                depositor_addr,
+                &setup.contract_wrapper,
+                TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.add_claim(
+                        &managed_address!(first_user_addr),
+                        CLAIM_TYPE_REWARD,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+    };
+    let clawback_claim = |b_wrapper: &mut BlockchainStateWrapper, caller: &Address| {
+        b_wrapper.execute_tx(caller, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.clawback_claim(
+                managed_address!(first_user_addr),
+                CLAIM_TYPE_REWARD,
+                managed_token_id_wrapped!(TOKEN_ID),
+            );
+        })
+    };
+
+    add_claim(b_wrapper, 300);
+    clawback_claim(b_wrapper, first_user_addr).assert_user_error(ERR_NOTHING_TO_CLAW_BACK);
+
+    b_wrapper.set_block_timestamp(1_050);
+    clawback_claim(b_wrapper, depositor_addr).assert_ok();
+    b_wrapper.check_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(1_000));
+
+    add_claim(b_wrapper, 200);
+    b_wrapper.set_block_timestamp(1_200);
+    clawback_claim(b_wrapper, depositor_addr).assert_user_error(ERR_CLAWBACK_GRACE_PERIOD_OVER);
+
+    add_claim(b_wrapper, 100);
+    b_wrapper.set_block_timestamp(1_290);
+    add_claim(b_wrapper, 1);
+    b_wrapper.set_block_timestamp(1_350);
+    clawback_claim(b_wrapper, depositor_addr).assert_ok();
+    b_wrapper.check_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(700));
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    clawback_claim(b_wrapper, depositor_addr).assert_user_error(ERR_NOTHING_TO_CLAW_BACK);
+    b_wrapper.check_esdt_balance(depositor_addr, TOKEN_ID, &rust_biguint!(700));
+    b_wrapper.check_esdt_balance(first_user_addr, TOKEN_ID, &rust_biguint!(1_300));
+}
+
+#[test] //Tests whether harvesting an explicit amount takes it from the claim types in their registration order and leaves the rest of the claims
+fn partial_harvest_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.first_user_address;
+
+    for (claim_type, amount) in [(CLAIM_TYPE_AIRDROP, 500u64), (CLAIM_TYPE_REWARD, 300u64)] {
+        b_wrapper
+            .execute_esdt_transfer(
+                &owner_address,
+                &setup.contract_wrapper,
+                TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.add_claim(
+                        &managed_address!(user_addr),
+                        claim_type,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    b_wrapper.set_block_timestamp(500);
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_amount(managed_token_id_wrapped!(TOKEN_ID), managed_biguint!(400));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_AIRDROP),
+                    OptionalValue::Some(managed_token_id_wrapped!(TOKEN_ID)),
+                    OptionalValue::Some(managed_biguint!(100)),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let token = managed_token_id_wrapped!(TOKEN_ID);
+            // The Reward claim type is registered before the Airdrop one and is harvested first
+            assert_eq!(
+                sc.claim(&managed_address!(user_addr), &CLAIM_TYPE_REWARD, &token)
+                    .get(),
+                0
+            );
+            assert_eq!(
+                sc.claim(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP, &token)
+                    .get(),
+                300
+            );
+            assert_eq!(
+                sc.claim_modify_date(&managed_address!(user_addr), &CLAIM_TYPE_AIRDROP)
+                    .get(),
+                500
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether the staking contract of a token must be a smart contract and must be set before its claims can be staked
+fn claim_and_stake_requires_staking_contract_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.first_user_address;
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_address!(user_addr),
+                    managed_buffer!(b"stake"),
+                );
+            },
+        )
+        .assert_user_error(ERR_NOT_A_SMART_CONTRACT);
+
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_and_stake(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_STAKING_CONTRACT_NOT_SET);
+}
+
+#[test] //Tests whether the harvested claims are sent to the staking contract on behalf of the address
+        //Tests whether a successful staking call leaves the claims, the reserved amounts and the campaign totals decreased
+fn claim_and_stake_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.first_user_address;
+
+    let staking_wrapper = b_wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(owner_address),
+        staking_mock::contract_obj,
+        STAKING_MOCK_WASM_PATH,
+    );
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_address!(staking_wrapper.address_ref()),
+                    managed_buffer!(b"stake"),
+                );
+                sc.create_campaign(
+                    managed_buffer!(b"Q3 community quests"),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.add_campaign_claim(
+                    1,
+                    managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_and_stake(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+    b_wrapper.check_esdt_balance(staking_wrapper.address_ref(), TOKEN_ID, &rust_biguint!(600));
+    b_wrapper.check_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        TOKEN_ID,
+        &rust_biguint!(0),
+    );
+
+    b_wrapper
+        .execute_query(&staking_wrapper, |sc| {
+            assert_eq!(sc.staked(&managed_address!(user_addr)).get(), 600);
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let address = managed_address!(user_addr);
+            let token = managed_token_id_wrapped!(TOKEN_ID);
+            assert_eq!(
+                sc.get_claim(&address, &CLAIM_TYPE_REWARD, &token),
+                managed_biguint!(0)
+            );
+            assert_eq!(sc.total_reserved(&token).get(), 0);
+            assert_eq!(sc.campaign(1).get().harvested, managed_biguint!(600));
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether a failed staking call restores the harvested claims together with their funding ledger and campaign shares
+fn claim_and_stake_failure_restores_claims_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.first_user_address;
+
+    // A second claims contract is used as staking contract, which has no stake endpoint so that the staking call fails
+    let staking_wrapper = b_wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(owner_address),
+        claims::contract_obj,
+        WASM_PATH,
+    );
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_address!(staking_wrapper.address_ref()),
+                    managed_buffer!(b"stake"),
+                );
+                sc.create_campaign(
+                    managed_buffer!(b"Q3 community quests"),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.add_campaign_claim(
+                    1,
+                    managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim_and_stake(
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.check_esdt_balance(user_addr, TOKEN_ID, &rust_biguint!(1_000));
+    b_wrapper.check_esdt_balance(staking_wrapper.address_ref(), TOKEN_ID, &rust_biguint!(0));
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let address = managed_address!(user_addr);
+            let token = managed_token_id_wrapped!(TOKEN_ID);
+            assert_eq!(
+                sc.get_claim(&address, &CLAIM_TYPE_REWARD, &token),
+                managed_biguint!(600)
+            );
+            assert_eq!(
+                sc.claim_funding(&address, &CLAIM_TYPE_REWARD, &token)
+                    .get(&managed_address!(owner_address))
+                    .unwrap(),
+                600
+            );
+            assert_eq!(
+                sc.claim_campaigns(&address, &CLAIM_TYPE_REWARD, &token)
+                    .get(&1)
+                    .unwrap(),
+                600
+            );
+            assert_eq!(sc.campaign(1).get().harvested, managed_biguint!(0));
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether harvested claims can only be sent to whitelisted functions of target contracts
+        //Tests whether the harvested claims are restored if the call of the target contract fails
+fn claim_and_execute_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.first_user_address;
+
+    // A second claims contract is used as target, in which the harvested tokens are added to a claim of the user
+    let target_wrapper = b_wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(owner_address),
+        claims::contract_obj,
+        WASM_PATH,
+    );
+    let claims_address = setup.contract_wrapper.address_ref().clone();
+    b_wrapper
+        .execute_tx(owner_address, &target_wrapper, &rust_biguint!(0), |sc| {
+            sc.init();
+            sc.add_claim_token(managed_token_id_wrapped!(TOKEN_ID));
+            sc.grant_role(Role::Depositor, managed_address!(&claims_address));
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    let claim_and_execute =
+        |b_wrapper: &mut BlockchainStateWrapper, function: &[u8], claim_type: u8| {
+            b_wrapper.execute_tx(
+                user_addr,
+                &setup.contract_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut args = MultiValueEncoded::new();
+                    args.push(managed_buffer!(user_addr.as_bytes()));
+                    args.push(managed_buffer!(&[claim_type]));
+                    sc.harvest_claim_and_execute(
+                        managed_address!(target_wrapper.address_ref()),
+                        managed_buffer!(function),
+                        managed_token_id_wrapped!(TOKEN_ID),
+                        args,
+                    );
+                },
+            )
+        };
+
+    claim_and_execute(b_wrapper, b"addClaim", CLAIM_TYPE_ALLOCATION)
+        .assert_user_error(ERR_EXECUTE_TARGET_NOT_WHITELISTED);
+
+    b_wrapper
+        .execute_tx(
+            owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_execute_target(
+                    managed_address!(target_wrapper.address_ref()),
+                    managed_buffer!(b"addClaim"),
+                );
+            },
+        )
+        .assert_ok();
+
+    // Only the whitelisted functions of a target contract can be called
+    claim_and_execute(b_wrapper, b"removeClaim", CLAIM_TYPE_ALLOCATION)
+        .assert_user_error(ERR_EXECUTE_TARGET_NOT_WHITELISTED);
+
+    // The call fails as the claim type is not registered in the target contract, so the claim of the user is restored
+    claim_and_execute(b_wrapper, b"addClaim", 9).assert_ok();
+    b_wrapper.check_esdt_balance(
+        setup.contract_wrapper.address_ref(),
+        TOKEN_ID,
+        &rust_biguint!(500),
+    );
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                ),
+                managed_biguint!(500)
+            );
+        })
+        .assert_ok();
+
+    claim_and_execute(b_wrapper, b"addClaim", CLAIM_TYPE_ALLOCATION).assert_ok();
+
+    b_wrapper
+        .execute_query(&target_wrapper, |sc| {
+            assert_eq!(
+                sc.claim(
+                    &managed_address!(user_addr),
+                    &CLAIM_TYPE_ALLOCATION,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(),
+                500
+            );
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether the claim movements of an address are recorded and only the most recent ones are kept once the limit is reached
+        //Tests whether the NFT and SFT claim movements are recorded and the movements kept under a higher previous limit are dropped
+fn claim_history_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let user_addr = &setup.first_user_address;
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_history_limit(2);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(300),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper.set_block_timestamp(100);
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(user_addr),
+                    CLAIM_TYPE_AIRDROP,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.claim_history_length(&managed_address!(user_addr)).get(),
+                3
+            );
+
+            // Only the latest two movements are kept, the latest one first
+            let history: Vec<_> = sc
+                .view_claim_history(&managed_address!(user_addr), 0, 10)
+                .into_iter()
+                .collect();
+            assert_eq!(history.len(), 2);
+            let added = &history[0];
+            assert_eq!(added.kind, ClaimMovementKind::Added);
+            assert_eq!(added.claim_type, CLAIM_TYPE_AIRDROP);
+            assert_eq!(added.amount, managed_biguint!(100));
+            assert_eq!(added.operator, managed_address!(owner_address));
+            let harvested = &history[1];
+            assert_eq!(harvested.kind, ClaimMovementKind::Harvested);
+            assert_eq!(harvested.amount, managed_biguint!(300));
+            assert_eq!(harvested.operator, managed_address!(user_addr));
+            assert_eq!(harvested.timestamp, 100);
+
+            let history = sc.view_claim_history(&managed_address!(user_addr), 1, 10);
+            assert_eq!(history.len(), 1);
+        })
+        .assert_ok();
+
+    // The NFT and SFT claim movements are recorded with their nonce
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 1, &rust_biguint!(5), &0u8);
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_history_limit(3);
+                sc.add_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(3),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_ok();
+
+    // Lowering the limit drops the movements kept under the previous limit once the next movement is recorded
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_history_limit(1);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::Some(CLAIM_TYPE_REWARD),
+                    OptionalValue::Some(managed_token_id_wrapped!(NFT_TOKEN_ID)),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let address = managed_address!(user_addr);
+            assert_eq!(sc.claim_history_length(&address).get(), 5);
+            for index in 1..5 {
+                assert!(sc.claim_history_entry(&address, index).is_empty());
+            }
+
+            let history: Vec<_> = sc.view_claim_history(&address, 0, 10).into_iter().collect();
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].kind, ClaimMovementKind::Harvested);
+            assert_eq!(history[0].token, managed_token_id_wrapped!(NFT_TOKEN_ID));
+            assert_eq!(history[0].nonce, 1);
+            assert_eq!(history[0].amount, managed_biguint!(3));
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether addresses enter the claimant registry when they get a claim and leave it once all their claims are harvested
+        //Tests whether the NFT and SFT claims are part of the claimant registry
+fn claimant_registry_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let second_user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue3(
+                    (
+                        managed_address!(first_user_addr),
+                        CLAIM_TYPE_REWARD,
+                        managed_biguint!(300),
+                    )
+                        .into(),
+                ));
+                args.push(MultiValue3(
+                    (
+                        managed_address!(second_user_addr),
+                        CLAIM_TYPE_REWARD,
+                        managed_biguint!(100),
+                    )
+                        .into(),
+                ));
+                args.push(MultiValue3(
+                    (
+                        managed_address!(second_user_addr),
+                        CLAIM_TYPE_AIRDROP,
+                        managed_biguint!(200),
+                    )
+                        .into(),
+                ));
+                sc.add_claims(args);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.view_claimants_count(), 2);
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.view_claimants_count(), 1);
+
+            let claimants: Vec<_> = sc.view_claimants(0, 10).into_iter().collect();
+            assert_eq!(claimants.len(), 1);
+            assert_eq!(claimants[0].address, managed_address!(second_user_addr));
+            assert_eq!(claimants[0].claims.len(), 2);
+            let claim = claimants[0].claims.get(1);
+            assert_eq!(claim.claim_type, CLAIM_TYPE_AIRDROP);
+            assert_eq!(claim.amount, managed_biguint!(200));
+
+            assert_eq!(sc.view_claimants(1, 10).len(), 0);
+        })
+        .assert_ok();
+
+    // An address with only NFT or SFT claims is part of the registry as well
+    let nft_user_addr = &setup.third_user_address;
+    b_wrapper.set_nft_balance(owner_address, NFT_TOKEN_ID, 1, &rust_biguint!(5), &0u8);
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_claim_token(managed_token_id_wrapped!(NFT_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            owner_address,
+            &setup.contract_wrapper,
+            NFT_TOKEN_ID,
+            1,
+            &rust_biguint!(3),
+            |sc| {
+                sc.add_nft_claim(&managed_address!(nft_user_addr), CLAIM_TYPE_REWARD);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.view_claimants_count(), 2);
+
+            let claimants: Vec<_> = sc.view_claimants(1, 10).into_iter().collect();
+            assert_eq!(claimants[0].address, managed_address!(nft_user_addr));
+            assert_eq!(claimants[0].claims.len(), 1);
+            let claim = claimants[0].claims.get(0);
+            assert_eq!(claim.token, managed_token_id_wrapped!(NFT_TOKEN_ID));
+            assert_eq!(claim.nonce, 1);
+            assert_eq!(claim.amount, managed_biguint!(3));
+        })
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            nft_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.view_claimants_count(), 1);
+        })
+        .assert_ok();
+}
+
+#[test] //Tests whether campaign claims are limited to the budget, token and claim type of the campaign and whether its statistics follow the harvests and removals
+fn campaign_test() {
+    let mut setup = setup_contract(claims::contract_obj);
+    let b_wrapper = &mut setup.blockchain_wrapper;
+    let owner_address = &setup.owner_address;
+    let first_user_addr = &setup.first_user_address;
+    let second_user_addr = &setup.second_user_address;
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let campaign_id = sc.create_campaign(
+                    managed_buffer!(b"Q3 community quests"),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(1_000),
+                );
+                assert_eq!(campaign_id, 1);
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(900),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(MultiValue3(
+                    (
+                        managed_address!(first_user_addr),
+                        CLAIM_TYPE_REWARD,
+                        managed_biguint!(600),
+                    )
+                        .into(),
+                ));
+                args.push(MultiValue3(
+                    (
+                        managed_address!(second_user_addr),
+                        CLAIM_TYPE_REWARD,
+                        managed_biguint!(300),
+                    )
+                        .into(),
+                ));
+                sc.add_campaign_claims(1, args);
+            },
+        )
+        .assert_ok();
+
+    for (claim_type, error) in [
+        (CLAIM_TYPE_AIRDROP, ERR_CAMPAIGN_CLAIM_TYPE_MISMATCH),
+        (CLAIM_TYPE_REWARD, ERR_CAMPAIGN_BUDGET_EXCEEDED),
+    ] {
+        b_wrapper
+            .execute_esdt_transfer(
+                &owner_address,
+                &setup.contract_wrapper,
+                TOKEN_ID,
+                0,
+                &rust_biguint!(200),
+                |sc| {
+                    sc.add_campaign_claim(
+                        1,
+                        managed_address!(first_user_addr),
+                        claim_type,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_user_error(error);
+    }
+
+    // Claims added outside of the campaign are not counted in it when harvested
+    b_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.contract_wrapper,
+            TOKEN_ID,
+            0,
+            &rust_biguint!(600),
+            |sc| {
+                sc.add_claim(
+                    &managed_address!(first_user_addr),
+                    CLAIM_TYPE_REWARD,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            first_user_addr,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.harvest_claim(
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_tx(
+            &owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_claim(
+                    &managed_address!(second_user_addr),
+                    CLAIM_TYPE_REWARD,
+                    managed_token_id_wrapped!(TOKEN_ID),
+                    managed_biguint!(100),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let campaign = sc.campaign(1).get();
+            assert_eq!(campaign.deposited, managed_biguint!(900));
+            assert_eq!(campaign.harvested, managed_biguint!(600));
+            assert_eq!(campaign.removed, managed_biguint!(100));
+            assert_eq!(
+                sc.claim_campaigns(
+                    &managed_address!(second_user_addr),
+                    &CLAIM_TYPE_REWARD,
+                    &managed_token_id_wrapped!(TOKEN_ID)
+                )
+                .get(&1)
+                .unwrap(),
+                200
+            );
+        })
+        .assert_ok();
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           20
-// Async Callback (empty):               1
-// Total number of exported functions:  22
+// Endpoints:                          111
+// Async Callback:                       1
+// Total number of exported functions: 113
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -18,27 +18,118 @@ multiversx_sc_wasm_adapter::panic_handler!();
 multiversx_sc_wasm_adapter::endpoints! {
     claims
     (
-        setClaimToken
+        addClaimToken
+        removeClaimToken
+        addClaimType
+        indexClaimants
+        setClaimHistoryLimit
+        setClaimTypeEnabled
+        withdrawSurplus
+        rescueTokens
         pause
         unpause
-        addPrivilegedAddress
-        removePrivilegedAddress
-        addDepositorAddress
-        removeDepositorAddress
+        pauseClaimType
+        unpauseClaimType
         addClaim
         addClaims
+        addClaimsWithExpiry
+        addCampaignClaim
+        addCampaignClaims
+        addVestedClaim
         removeClaim
         removeClaims
+        executeOperation
+        reclaimExpiredClaims
+        setBeneficiary
+        clearBeneficiary
         claim
+        claimAmount
+        claimAndStake
+        claimAndExecute
+        claimTo
+        claimFor
+        viewClaimTypes
+        viewClaimTokens
         viewTokenIdentifier
-        viewClaim
+        viewNftClaimFunding
+        viewNftReserved
         viewClaimModifyDate
+        viewVestingSchedule
+        viewClaimExpiry
+        viewBeneficiary
+        viewClaimTypeBeneficiary
+        viewRelayedClaimNonce
+        viewTotalReserved
+        viewTotalReservedByType
         isPaused
-        viewPrivilegedAddresses
-        viewDepositorAddresses
+        viewPausedClaimTypes
+        viewAdmin
+        viewPendingAdmin
+        viewTimelockDelay
+        viewQueuedOperations
+        viewRoleMembers
+        viewClaimFunding
+        viewClawbackGracePeriod
+        viewClaimDeposits
+        viewStakingContract
+        viewExecuteTargets
+        viewDepositorClaimTypes
+        viewDepositorQuota
+        viewLastDistributionId
+        viewDistribution
+        viewVoucherSigner
+        viewVoucherPool
+        isVoucherNonceUsed
+        viewLastCampaignId
+        viewCampaign
+        viewClaimCampaigns
+        viewClaimHistoryLimit
+        viewClaimHistoryLength
+        viewClaim
         viewClaims
         viewClaimWithDate
+        viewLockedClaim
+        isClaimExpired
+        viewClaimsWithVesting
+        viewClaimReceiver
+        viewEgldClaims
+        viewEsdtClaims
+        viewNftClaims
+        isDistributionLeafClaimed
+        viewSolvency
+        hasRole
+        viewAddressRoles
+        viewClaimantsCount
+        viewClaimants
+        viewClaimHistory
+        createDistribution
+        claimDistribution
+        closeDistribution
+        createCampaign
+        setDepositorClaimTypes
+        setDepositorQuota
+        clearDepositorQuota
+        setClawbackGracePeriod
+        clawbackClaim
+        viewRemainingDepositorQuota
+        setVoucherSigner
+        fundVoucherPool
+        withdrawVoucherPool
+        redeemVoucher
+        proposeAdmin
+        acceptAdmin
+        setTimelockDelay
+        cancelOperation
+        addNftClaim
+        removeNftClaim
+        grantRole
+        revokeRole
+        renounceRole
+        setStakingContract
+        clearStakingContract
+        addExecuteTarget
+        removeExecuteTarget
     )
 }
 
-multiversx_sc_wasm_adapter::empty_callback! {}
+multiversx_sc_wasm_adapter::callback! { claims }